@@ -1,5 +1,10 @@
+use std::cell::RefCell;
+use std::collections::BTreeSet;
 use std::env;
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use bindgen::callbacks::{ItemInfo, ItemKind, ParseCallbacks};
 
 fn target_has_feature(feature: &str) -> bool {
     env::var("CARGO_CFG_TARGET_FEATURE")
@@ -14,7 +19,7 @@ pub struct CustomCargoCallbacks {
     exclude_dir: Option<PathBuf>,
 }
 
-impl bindgen::callbacks::ParseCallbacks for CustomCargoCallbacks {
+impl ParseCallbacks for CustomCargoCallbacks {
     fn header_file(&self, filename: &str) {
         if matches!(&self.exclude_dir, Some(exclude_dir) if Path::new(filename).starts_with(exclude_dir))
         {
@@ -36,10 +41,243 @@ impl bindgen::callbacks::ParseCallbacks for CustomCargoCallbacks {
     }
 }
 
+/// Collects the names of the `Highs_*` functions in the headers, for `write_function_list`.
+#[derive(Debug, Default)]
+struct FunctionNames(Rc<RefCell<BTreeSet<String>>>);
+
+impl ParseCallbacks for FunctionNames {
+    fn generated_name_override(&self, item_info: ItemInfo<'_>) -> Option<String> {
+        if matches!(item_info.kind, ItemKind::Function) && item_info.name.starts_with("Highs_") {
+            self.0.borrow_mut().insert(item_info.name.to_string());
+        }
+        None
+    }
+}
+
+/// The oldest HiGHS release supported by `discover()`.
+const MIN_HIGHS_MINOR: u32 = 5;
+/// Minor releases for which a `highs_1_x` cfg is declared even if the linked HiGHS is older,
+/// so that code gated on them does not trigger `unexpected_cfgs` warnings.
+const MAX_KNOWN_HIGHS_MINOR: u32 = 15;
+
+/// Reads the first `HConfig.h` found in `include_paths`.
+fn read_config<'a>(include_paths: impl IntoIterator<Item = &'a Path>) -> Option<String> {
+    include_paths
+        .into_iter()
+        .find_map(|path| std::fs::read_to_string(path.join("HConfig.h")).ok())
+}
+
+/// Returns the value of `#define name value` in `config`.
+fn config_value<'a>(config: &'a str, name: &str) -> Option<&'a str> {
+    config.lines().find_map(|line| {
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next(), words.next()) {
+            (Some("#define"), Some(n), Some(value)) if n == name => Some(value),
+            _ => None,
+        }
+    })
+}
+
+/// Returns whether `config` contains a `#define name`, with or without a value.
+fn config_defines(config: &str, name: &str) -> bool {
+    config.lines().any(|line| {
+        let mut words = line.split_whitespace();
+        words.next() == Some("#define") && words.next() == Some(name)
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct HighsVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl HighsVersion {
+    /// Parses a version of the form `major.minor.patch`, as reported by pkg-config.
+    #[cfg(feature = "discover")]
+    fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.trim().split('.').map(|part| part.parse().ok());
+        Some(HighsVersion {
+            major: parts.next()??,
+            minor: parts.next()??,
+            patch: parts.next().unwrap_or(Some(0))?,
+        })
+    }
+
+    /// Reads the version from the `HIGHS_VERSION_*` defines in `HConfig.h`.
+    fn from_config<'a>(include_paths: impl IntoIterator<Item = &'a Path>) -> Option<Self> {
+        let config = read_config(include_paths)?;
+        let define = |name: &str| config_value(&config, name)?.parse().ok();
+        Some(HighsVersion {
+            major: define("HIGHS_VERSION_MAJOR")?,
+            minor: define("HIGHS_VERSION_MINOR")?,
+            patch: define("HIGHS_VERSION_PATCH")?,
+        })
+    }
+}
+
+impl HighsVersion {
+    /// Reads the version of a HiGHS source tree from its `Version.txt`, which contains
+    /// `HIGHS_MAJOR=`, `HIGHS_MINOR=` and `HIGHS_PATCH=` lines.
+    #[cfg(any(feature = "build", feature = "build-cc"))]
+    fn from_version_file(path: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        let field = |name: &str| {
+            contents.lines().find_map(|line| {
+                let (key, value) = line.split_once('=')?;
+                if key.trim() == name {
+                    value.trim().parse().ok()
+                } else {
+                    None
+                }
+            })
+        };
+        Some(HighsVersion {
+            major: field("HIGHS_MAJOR")?,
+            minor: field("HIGHS_MINOR")?,
+            patch: field("HIGHS_PATCH")?,
+        })
+    }
+}
+
+impl std::fmt::Display for HighsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Lets the crate adapt to the HiGHS it is compiled against: enables a `highs_1_x` cfg for
+/// every minor release up to `version`, and writes the `HIGHS_VERSION_*` constants.
+fn emit_version_config(version: HighsVersion) {
+    let max_known = version.minor.max(MAX_KNOWN_HIGHS_MINOR);
+    let known: Vec<String> = (MIN_HIGHS_MINOR..=max_known)
+        .map(|minor| format!("highs_1_{minor}"))
+        .collect();
+    println!("cargo:rustc-check-cfg=cfg({})", known.join(", "));
+    let enabled_up_to = match version.major {
+        0 => 0,
+        1 => version.minor,
+        _ => max_known,
+    };
+    for minor in MIN_HIGHS_MINOR..=enabled_up_to {
+        println!("cargo:rustc-cfg=highs_1_{minor}");
+    }
+
+    let constants = format!(
+        "/// Major version of the HiGHS headers the bindings were generated from.
+pub const HIGHS_VERSION_MAJOR: HighsInt = {};
+/// Minor version of the HiGHS headers the bindings were generated from.
+pub const HIGHS_VERSION_MINOR: HighsInt = {};
+/// Patch version of the HiGHS headers the bindings were generated from.
+pub const HIGHS_VERSION_PATCH: HighsInt = {};
+",
+        version.major, version.minor, version.patch
+    );
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    std::fs::write(out_path.join("highs_version.rs"), constants)
+        .expect("Couldn't write HiGHS version constants!");
+}
+
+/// Passes metadata about the HiGHS we link to dependent crates. Because of `links = "highs"`,
+/// they can read it from the `DEP_HIGHS_INCLUDE`, `DEP_HIGHS_LIB_DIR`, `DEP_HIGHS_VERSION`
+/// and `DEP_HIGHS_STATIC` environment variables in their own build scripts.
+fn emit_link_metadata<'a>(
+    include_paths: impl IntoIterator<Item = &'a Path>,
+    lib_dirs: impl IntoIterator<Item = &'a Path>,
+    version: HighsVersion,
+    statik: bool,
+) {
+    let include = env::join_paths(include_paths).expect("Invalid HiGHS include path");
+    let lib_dir = env::join_paths(lib_dirs).expect("Invalid HiGHS library path");
+    println!("cargo:include={}", include.to_string_lossy());
+    println!("cargo:lib_dir={}", lib_dir.to_string_lossy());
+    println!("cargo:version={version}");
+    println!("cargo:static={statik}");
+}
+
+/// The date HiGHS was built or discovered, as `YYYY-MM-DD`.
+/// `SOURCE_DATE_EPOCH` overrides the current time, for reproducible builds.
+fn compilation_date() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    let seconds = env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse::<i64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("The system clock is set before 1970")
+                .as_secs() as i64
+        });
+    // Days since 1970-01-01 to a proleptic Gregorian date, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = seconds.div_euclid(86400) + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Writes the constants behind `highs_sys::build_info()`. `source` is the name of a
+/// `HighsSource` variant, and `profile` the CMake build type if it is known.
+fn emit_build_info<'a>(
+    include_paths: impl IntoIterator<Item = &'a Path>,
+    source: &str,
+    statik: bool,
+    profile: Option<&str>,
+) {
+    let config = read_config(include_paths).unwrap_or_default();
+    let zlib = config_defines(&config, "ZLIB_FOUND");
+    let profile = profile.map(str::to_string).or_else(|| {
+        Some(
+            config_value(&config, "CMAKE_BUILD_TYPE")?
+                .trim_matches('"')
+                .to_string(),
+        )
+    });
+
+    let constants = format!(
+        "const SOURCE: HighsSource = HighsSource::{source};
+const STATIC_LINK: bool = {statik};
+const PROFILE: Option<&str> = {profile:?};
+pub(crate) const ZLIB: bool = {zlib};
+const COMPILATION_DATE: &str = {date:?};
+",
+        date = compilation_date()
+    );
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    std::fs::write(out_path.join("build_info.rs"), constants)
+        .expect("Couldn't write build info constants!");
+}
+
+/// Compiles `layout_probes.c`, which records the size, alignment and field offsets of the
+/// HiGHS C API structs as computed by the C compiler for the target. Only
+/// `tests/test_layout.rs` uses them, so they are only linked with the `layout-probes` feature.
+fn compile_layout_probes(include_paths: &[&Path]) {
+    println!("cargo:rerun-if-changed=layout_probes.c");
+    cc::Build::new()
+        .file("layout_probes.c")
+        .includes(include_paths)
+        .warnings(false)
+        .compile("highs_sys_layout_probes");
+}
+
 fn generate_bindings<'a>(
     exclude_dir: Option<PathBuf>,
     include_paths: impl IntoIterator<Item = &'a Path>,
 ) {
+    let include_paths: Vec<&Path> = include_paths.into_iter().collect();
+    if cfg!(feature = "layout-probes") {
+        compile_layout_probes(&include_paths);
+    }
+
     let target = env::var("TARGET").unwrap();
     let emscripten = target.contains("emscripten");
 
@@ -47,7 +285,7 @@ fn generate_bindings<'a>(
     // to bindgen, and lets you build up options for
     // the resulting bindings.
     let builder = include_paths
-        .into_iter()
+        .iter()
         .fold(bindgen::Builder::default(), |builder, path| {
             builder.clang_arg(format!("-I{}", path.to_string_lossy()))
         });
@@ -55,7 +293,8 @@ fn generate_bindings<'a>(
     // Work around bindgen/libclang missing `Highs_*` function declarations for
     // `wasm32-unknown-emscripten` when parsing with emscripten's sysroot.
     // The C API signatures are target-independent, so parse as host and skip
-    // cross-target layout assertions.
+    // cross-target layout assertions. The struct layouts are still checked
+    // against the target's C compiler, see `compile_layout_probes`.
     let builder = if emscripten {
         println!("cargo:rerun-if-env-changed=HOST");
         let host = env::var("HOST")
@@ -67,14 +306,24 @@ fn generate_bindings<'a>(
         builder
     };
 
+    let function_names = FunctionNames::default();
+    let functions = Rc::clone(&function_names.0);
     let c_bindings = builder
         // The input header we would like to generate bindings for.
         // This is a trivial wrapper header so that the HiGHS headers
         // can be discovered from the include path.
         .header("wrapper.h")
+        // Only generate bindings for the HiGHS C API: its functions, its `kHighs*`
+        // constants and the types they use, such as the callback data structs.
+        // Otherwise, the bindings would also contain whatever the system headers define.
+        .allowlist_function("Highs_.*")
+        .allowlist_var("kHighs.*")
+        .allowlist_type("Highs.*")
+        .derive_default(true)
         // Tell cargo to invalidate the built crate whenever any of the
         // included header files changed.
         .parse_callbacks(Box::new(CustomCargoCallbacks { exclude_dir }))
+        .parse_callbacks(Box::new(function_names))
         // Finish the builder and generate the bindings.
         .generate()
         // Unwrap the Result and panic on failure.
@@ -85,14 +334,57 @@ fn generate_bindings<'a>(
     c_bindings
         .write_to_file(out_path.join("c_bindings.rs"))
         .expect("Couldn't write bindings!");
+
+    write_function_list(&functions.borrow(), &out_path.join("highs_functions.rs"));
+}
+
+/// Writes a `highs_functions()` function returning the name and address of every `Highs_*`
+/// function in the bindings. Taking their addresses makes the linker resolve all of them,
+/// so `tests/test_link_completeness.rs` fails if the library misses any declared function.
+fn write_function_list(functions: &BTreeSet<String>, path: &Path) {
+    let entries: String = functions
+        .iter()
+        .map(|name| format!("        ({name:?}, {name} as *const ()),\n"))
+        .collect();
+    let list = format!(
+        "#[allow(deprecated)]
+fn highs_functions() -> Vec<(&'static str, *const ())> {{
+    vec![
+{entries}    ]
+}}
+"
+    );
+    std::fs::write(path, list).expect("Couldn't write the list of HiGHS functions!");
+}
+
+/// The CMake build type for HiGHS, whose compiler flags the `cc` build mirrors.
+#[cfg(any(feature = "build", feature = "build-cc"))]
+fn cmake_profile() -> &'static str {
+    // Avoid using downstream project's profile setting for HiGHS build.
+    // The Debug profile keeps HiGHS' assertions enabled.
+    if cfg!(feature = "highs_debug") {
+        return "Debug";
+    }
+    if cfg!(feature = "highs_release") {
+        return "Release";
+    }
+    // Otherwise, follow the Cargo profile the same way the `cmake` crate does by default.
+    let debug_info = env::var("DEBUG").is_ok_and(|debug| debug != "false" && debug != "0");
+    match env::var("OPT_LEVEL").as_deref() {
+        Ok("0") => "Debug",
+        Ok("s") | Ok("z") => "MinSizeRel",
+        _ if debug_info => "RelWithDebInfo",
+        _ => "Release",
+    }
 }
 
+/// Builds HiGHS with CMake and returns the directory it was installed to.
 #[cfg(feature = "build")]
-fn build() -> bool {
+fn cmake_build(target: &str) -> PathBuf {
     use cmake::Config;
-    let target = env::var("TARGET").unwrap();
     let emscripten = target.contains("emscripten");
-    let mut dst = Config::new("HiGHS");
+    let source_dir = highs_source_dir();
+    let mut dst = Config::new(&source_dir);
     let crt_static = target_has_feature("crt-static");
 
     if cfg!(feature = "ninja") {
@@ -109,9 +401,29 @@ fn build() -> bool {
         dst.cxxflag("-fexceptions");
     }
 
-    // Avoid using downstream project's profile setting for HiGHS build.
-    if cfg!(feature = "highs_release") {
-        dst.profile("Release");
+    dst.profile(cmake_profile());
+
+    // Point CMake's FindZLIB at the zlib that libz-sys links, rather than any installed zlib.
+    // `DEP_Z_ROOT` is only set when libz-sys builds zlib itself.
+    if cfg!(feature = "libz") {
+        println!("cargo:rerun-if-env-changed=DEP_Z_ROOT");
+        println!("cargo:rerun-if-env-changed=DEP_Z_INCLUDE");
+        if let Some(root) = env::var_os("DEP_Z_ROOT") {
+            dst.define("ZLIB_ROOT", root);
+        }
+        if let Some(include) = env::var_os("DEP_Z_INCLUDE") {
+            dst.define("ZLIB_INCLUDE_DIR", include);
+        }
+    }
+
+    #[cfg(feature = "hipo")]
+    configure_hipo(&mut dst, &source_dir, target);
+
+    if cfg!(feature = "highs_asan") {
+        for flag in ASAN_FLAGS {
+            dst.cflag(flag);
+            dst.cxxflag(flag);
+        }
     }
 
     let dst = dst
@@ -127,17 +439,144 @@ fn build() -> bool {
         )
         .define("CMAKE_INTERPROCEDURAL_OPTIMIZATION", "FALSE")
         .define("ZLIB", if cfg!(feature = "libz") { "ON" } else { "OFF" })
+        .define(
+            "HIGHSINT64",
+            if cfg!(feature = "highsint64") {
+                "ON"
+            } else {
+                "OFF"
+            },
+        )
         .build();
 
+    println!(
+        "cargo:rerun-if-changed={}",
+        source_dir
+            .join("highs")
+            .join("interfaces")
+            .join("highs_c_api.h")
+            .display()
+    );
+
+    dst
+}
+
+/// Checks that the HiGHS installed in `HIGHS_PREBUILT_DIR` can stand in for the one
+/// `cmake_build` would produce: same version as the bundled sources and same build options.
+#[cfg(feature = "build")]
+fn check_prebuilt(prebuilt_dir: &Path) {
+    let include_path = prebuilt_dir.join("include").join("highs");
+    let config = std::fs::read_to_string(include_path.join("HConfig.h")).unwrap_or_else(|_| {
+        panic!(
+            "HIGHS_PREBUILT_DIR is set to {}, but it does not contain include/highs/HConfig.h. \
+             It should be the install prefix of a HiGHS build, as produced by `cmake --install`.",
+            prebuilt_dir.display()
+        )
+    });
+    println!(
+        "cargo:rerun-if-changed={}",
+        include_path.join("HConfig.h").display()
+    );
+
+    let prebuilt = HighsVersion::from_config([include_path.as_path()])
+        .expect("Couldn't read the HiGHS version from the prebuilt HConfig.h");
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    match HighsVersion::from_version_file(&manifest_dir.join("HiGHS").join("Version.txt")) {
+        Some(bundled) if bundled != prebuilt => panic!(
+            "The HiGHS in HIGHS_PREBUILT_DIR is version {}, but highs-sys bundles HiGHS {}. \
+             Please rebuild it from the bundled sources.",
+            prebuilt, bundled
+        ),
+        Some(_) => (),
+        None => println!(
+            "cargo::warning=Couldn't read the version of the bundled HiGHS sources, so the HiGHS {prebuilt} in HIGHS_PREBUILT_DIR could not be checked against it."
+        ),
+    }
+
+    let defined = |name: &str| config_defines(&config, name);
+    for (option, feature, enabled) in [
+        ("HIGHSINT64", "highsint64", cfg!(feature = "highsint64")),
+        ("ZLIB_FOUND", "libz", cfg!(feature = "libz")),
+        ("HIPO", "hipo", cfg!(feature = "hipo")),
+    ] {
+        if defined(option) != enabled {
+            panic!(
+                "The HiGHS in HIGHS_PREBUILT_DIR was built with {option} {}, but the '{feature}' feature is {}.",
+                if defined(option) { "enabled" } else { "disabled" },
+                if enabled { "enabled" } else { "disabled" },
+            );
+        }
+    }
+}
+
+#[cfg(feature = "build")]
+fn build() -> bool {
+    let target = env::var("TARGET").unwrap();
+
+    // A prebuilt HiGHS lets caches share one HiGHS build across many workspaces and targets.
+    println!("cargo:rerun-if-env-changed=HIGHS_PREBUILT_DIR");
+    let (dst, exclude_dir, source, profile) = match env::var_os("HIGHS_PREBUILT_DIR") {
+        Some(prebuilt_dir) => {
+            let prebuilt_dir = PathBuf::from(prebuilt_dir);
+            check_prebuilt(&prebuilt_dir);
+            (prebuilt_dir, None, "Prebuilt", None)
+        }
+        None => {
+            let dst = cmake_build(&target);
+            (dst.clone(), Some(dst), "Bundled", Some(cmake_profile()))
+        }
+    };
+
     let include_path = dst.join("include").join("highs");
-    generate_bindings(Some(dst.clone()), [include_path.as_path()]);
+    emit_build_info([include_path.as_path()], source, true, profile);
+    link_static_highs(&target, &dst, &[include_path], exclude_dir);
+
+    true
+}
+
+/// Generates the bindings for a HiGHS built by this script into `dst`, and links its static
+/// library from `dst/lib` or `dst/lib64`.
+#[cfg(any(feature = "build", feature = "build-cc"))]
+fn link_static_highs(
+    target: &str,
+    dst: &Path,
+    include_paths: &[PathBuf],
+    exclude_dir: Option<PathBuf>,
+) {
+    let emscripten = target.contains("emscripten");
+    generate_bindings(exclude_dir, include_paths.iter().map(|p| p.as_path()));
+
+    // Depending on the platform, CMake installs the library to either `lib` or `lib64`.
+    let lib_dir = if dst.join("lib64").exists() {
+        dst.join("lib64")
+    } else {
+        dst.join("lib")
+    };
+    let version = HighsVersion::from_config(include_paths.iter().map(|p| p.as_path()))
+        .expect("Couldn't read the HiGHS version from HConfig.h");
+    emit_version_config(version);
+    emit_link_metadata(
+        include_paths.iter().map(|p| p.as_path()),
+        [lib_dir.as_path()],
+        version,
+        true,
+    );
 
     println!("cargo:rustc-link-search=native={}/lib", dst.display());
     println!("cargo:rustc-link-search=native={}/lib64", dst.display());
+    // With the `libz` feature, zlib is linked by the libz-sys dependency.
     println!("cargo:rustc-link-lib=static=highs");
 
-    if cfg!(feature = "libz") {
-        println!("cargo:rustc-link-lib=z");
+    // BLAS must come after HiGHS on the link line, since static HiGHS depends on it.
+    #[cfg(all(feature = "build", feature = "hipo"))]
+    {
+        let blas = find_blas(target);
+        for path in &blas.link_paths {
+            println!("cargo:rustc-link-search=native={}", path.display());
+        }
+        for lib in &blas.libs {
+            println!("cargo:rustc-link-lib={}", lib);
+        }
     }
 
     let apple = target.contains("apple");
@@ -148,9 +587,110 @@ fn build() -> bool {
     } else if linux || mingw {
         println!("cargo:rustc-link-lib=stdc++");
     }
-    println!("cargo:rerun-if-changed=HiGHS/highs/interfaces/highs_c_api.h");
 
-    true
+    if cfg!(feature = "highs_asan") {
+        link_asan_runtime(target);
+    }
+}
+
+/// The BLAS library that the HiPO interior point solver of the `hipo` feature calls into.
+#[cfg(all(feature = "build", feature = "hipo"))]
+struct Blas {
+    /// The `BLA_VENDOR` that makes CMake's FindBLAS pick this library.
+    cmake_vendor: &'static str,
+    link_paths: Vec<PathBuf>,
+    /// The libraries to link, as `cargo:rustc-link-lib` values.
+    libs: Vec<String>,
+}
+
+/// Finds a BLAS for HiPO: Accelerate on Apple targets, otherwise OpenBLAS or the reference
+/// BLAS through pkg-config. Nothing is linked here, as BLAS must come after HiGHS.
+#[cfg(all(feature = "build", feature = "hipo"))]
+fn find_blas(target: &str) -> Blas {
+    if target.contains("apple") {
+        return Blas {
+            cmake_vendor: "Apple",
+            link_paths: Vec::new(),
+            libs: vec!["framework=Accelerate".to_string()],
+        };
+    }
+    ["openblas", "blas"]
+        .iter()
+        .find_map(|name| {
+            pkg_config::Config::new()
+                .cargo_metadata(false)
+                .probe(name)
+                .ok()
+        })
+        .map(|lib| Blas {
+            cmake_vendor: if lib.libs.iter().any(|l| l.contains("openblas")) {
+                "OpenBLAS"
+            } else {
+                "Generic"
+            },
+            link_paths: lib.link_paths,
+            libs: lib.libs,
+        })
+        .unwrap_or_else(|| {
+            panic!(
+                "The 'hipo' feature needs a BLAS library, but pkg-config found neither 'openblas' nor 'blas'. \
+                 Please install OpenBLAS, e.g. `sudo apt install libopenblas-dev`, or set PKG_CONFIG_PATH to where its .pc file is."
+            )
+        })
+}
+
+/// Enables the HiPO interior point solver in the CMake build, pointing it at the BLAS from
+/// `find_blas` so that HiGHS is compiled against the library that is linked.
+#[cfg(all(feature = "build", feature = "hipo"))]
+fn configure_hipo(dst: &mut cmake::Config, source_dir: &Path, target: &str) {
+    // HiPO was added in HiGHS 1.12.
+    if let Some(version) = HighsVersion::from_version_file(&source_dir.join("Version.txt")) {
+        if (version.major, version.minor) < (1, 12) {
+            panic!(
+                "The 'hipo' feature needs HiGHS 1.12 or newer, but the HiGHS sources are version {}.",
+                version
+            );
+        }
+    }
+
+    let blas = find_blas(target);
+    dst.define("HIPO", "ON");
+    dst.define("BLA_VENDOR", blas.cmake_vendor);
+    if let Some(prefix) = blas.link_paths.first().and_then(|dir| dir.parent()) {
+        dst.define("CMAKE_PREFIX_PATH", prefix);
+    }
+}
+
+/// Compiler flags instrumenting HiGHS with AddressSanitizer, for the `highs_asan` feature.
+#[cfg(any(feature = "build", feature = "build-cc"))]
+const ASAN_FLAGS: [&str; 2] = ["-fsanitize=address", "-fno-omit-frame-pointer"];
+
+/// Links the AddressSanitizer runtime that an instrumented HiGHS calls into. Rust code itself
+/// is not instrumented, so nothing else would link it.
+#[cfg(any(feature = "build", feature = "build-cc"))]
+fn link_asan_runtime(target: &str) {
+    if target.contains("linux") {
+        // GCC's runtime, which the linker finds on its own.
+        println!("cargo:rustc-link-lib=dylib=asan");
+    } else if target.contains("apple") {
+        // Clang's runtime lives in the compiler's resource directory.
+        let compiler = cc::Build::new().cpp(true).get_compiler();
+        let output = compiler
+            .to_command()
+            .arg("-print-file-name=libclang_rt.asan_osx_dynamic.dylib")
+            .output()
+            .expect("Couldn't ask the C++ compiler for the AddressSanitizer runtime");
+        let runtime = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+        let runtime_dir = runtime
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .expect("Couldn't find the AddressSanitizer runtime of the C++ compiler");
+        println!("cargo:rustc-link-search=native={}", runtime_dir.display());
+        println!("cargo:rustc-link-arg=-Wl,-rpath,{}", runtime_dir.display());
+        println!("cargo:rustc-link-lib=dylib=clang_rt.asan_osx_dynamic");
+    } else {
+        panic!("The 'highs_asan' feature is only supported on Linux and macOS targets");
+    }
 }
 
 #[cfg(not(feature = "build"))]
@@ -158,6 +698,207 @@ fn build() -> bool {
     false
 }
 
+/// Emulates CMake's `configure_file` for `HConfig.h.in`: `#cmakedefine` lines are kept for the
+/// variables in `defined`, and `@VAR@` placeholders are replaced with the matching `values`.
+#[cfg(feature = "build-cc")]
+fn configure_file(template: &str, defined: &[&str], values: &[(&str, String)]) -> String {
+    let substitute = |line: &str| {
+        values.iter().fold(line.to_string(), |line, (name, value)| {
+            line.replace(&format!("@{name}@"), value)
+                .replace(&format!("${{{name}}}"), value)
+        })
+    };
+    template
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            if let Some(rest) = trimmed.strip_prefix("#cmakedefine01") {
+                let name = rest.trim();
+                let value = u8::from(defined.contains(&name));
+                format!("#define {name} {value}")
+            } else if let Some(rest) = trimmed.strip_prefix("#cmakedefine") {
+                let name = rest.split_whitespace().next().unwrap_or_default();
+                if defined.contains(&name) {
+                    substitute(&format!("#define{rest}"))
+                } else {
+                    format!("/* #undef {name} */")
+                }
+            } else {
+                substitute(line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Collects the C and C++ sources of the HiGHS library, and the directories containing its
+/// headers, skipping the Python bindings and the optional HiPO solver.
+#[cfg(feature = "build-cc")]
+fn collect_sources(
+    dir: &Path,
+    c_sources: &mut Vec<PathBuf>,
+    cpp_sources: &mut Vec<PathBuf>,
+    header_dirs: &mut Vec<PathBuf>,
+) {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Couldn't read {}: {}", dir.display(), e))
+        .map(|entry| entry.expect("Couldn't read directory entry").path())
+        .collect();
+    entries.sort();
+
+    let mut has_headers = false;
+    for path in entries {
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        if path.is_dir() {
+            if name != "highspy" && name != "hipo" {
+                collect_sources(&path, c_sources, cpp_sources, header_dirs);
+            }
+            continue;
+        }
+        // Of the language interfaces, only the C API is part of the library.
+        if dir.ends_with("interfaces") && name != "highs_c_api.cpp" && name != "highs_c_api.h" {
+            continue;
+        }
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("c") => c_sources.push(path),
+            Some("cpp") => cpp_sources.push(path),
+            Some("h") | Some("hpp") => has_headers = true,
+            _ => (),
+        }
+    }
+    if has_headers {
+        header_dirs.push(dir.to_path_buf());
+    }
+}
+
+/// Builds HiGHS with the `cc` crate rather than CMake, into the same layout as `cmake_build`.
+#[cfg(feature = "build-cc")]
+fn build_cc() -> bool {
+    if cfg!(feature = "hipo") {
+        panic!("The 'hipo' feature is only supported by the CMake build. Please enable 'build' rather than 'build-cc'.");
+    }
+
+    let target = env::var("TARGET").unwrap();
+    let emscripten = target.contains("emscripten");
+    let msvc = target.contains("msvc");
+    let x86 = target.starts_with("x86_64") || target.starts_with("i686");
+
+    let source_dir = highs_source_dir();
+    let highs_dir = source_dir.join("highs");
+    println!("cargo:rerun-if-changed={}", highs_dir.display());
+
+    let dst = PathBuf::from(env::var("OUT_DIR").unwrap()).join("highs-cc");
+    let config_dir = dst.join("include").join("highs");
+    std::fs::create_dir_all(&config_dir).expect("Couldn't create the HiGHS include directory");
+
+    // Generate HConfig.h with the same settings as the CMake build.
+    let version = HighsVersion::from_version_file(&source_dir.join("Version.txt"))
+        .expect("Couldn't read the HiGHS version from Version.txt");
+    let template_path = vec![
+        highs_dir.join("HConfig.h.in"),
+        source_dir.join("HConfig.h.in"),
+    ]
+    .into_iter()
+    .find(|path| path.exists())
+    .expect("Couldn't find HConfig.h.in in the HiGHS sources");
+    let template = std::fs::read_to_string(&template_path).expect("Couldn't read HConfig.h.in");
+    let mut defined = vec!["FAST_BUILD", "CUPDLP_CPU", "CMAKE_BUILD_TYPE"];
+    if cfg!(feature = "highsint64") {
+        defined.push("HIGHSINT64");
+    }
+    if cfg!(feature = "libz") {
+        defined.push("ZLIB_FOUND");
+    }
+    if x86 {
+        defined.push("HIGHS_HAVE_MM_PAUSE");
+    }
+    if msvc {
+        defined.push("HIGHS_HAVE_BITSCAN_REVERSE");
+    } else {
+        defined.push("HIGHS_HAVE_BUILTIN_CLZ");
+    }
+    let build_type = cmake_profile();
+    let values = [
+        ("HIGHS_VERSION_MAJOR", version.major.to_string()),
+        ("HIGHS_VERSION_MINOR", version.minor.to_string()),
+        ("HIGHS_VERSION_PATCH", version.patch.to_string()),
+        ("GITHASH", "n/a".to_string()),
+        ("CMAKE_BUILD_TYPE", build_type.to_string()),
+    ];
+    std::fs::write(
+        config_dir.join("HConfig.h"),
+        configure_file(&template, &defined, &values),
+    )
+    .expect("Couldn't write HConfig.h");
+
+    let mut c_sources = Vec::new();
+    let mut cpp_sources = Vec::new();
+    let mut include_dirs = vec![config_dir.clone(), highs_dir.clone()];
+    collect_sources(
+        &highs_dir,
+        &mut c_sources,
+        &mut cpp_sources,
+        &mut include_dirs,
+    );
+    let extern_dir = source_dir.join("extern");
+    if extern_dir.exists() {
+        include_dirs.push(extern_dir);
+    }
+    // Compile against the zlib headers of libz-sys, when it builds zlib itself.
+    if cfg!(feature = "libz") {
+        println!("cargo:rerun-if-env-changed=DEP_Z_INCLUDE");
+        if let Some(include) = env::var_os("DEP_Z_INCLUDE") {
+            include_dirs.push(PathBuf::from(include));
+        }
+    }
+
+    let configure = |build: &mut cc::Build| {
+        build
+            .includes(&include_dirs)
+            .warnings(false)
+            .cargo_metadata(false)
+            .out_dir(dst.join("lib"));
+        // Use the flags CMake uses for the build type, see `cmake_profile`.
+        match build_type {
+            "Debug" => build.opt_level(0).debug(true),
+            "RelWithDebInfo" => build.opt_level(2).debug(true).define("NDEBUG", None),
+            "MinSizeRel" => build.opt_level_str("s").debug(false).define("NDEBUG", None),
+            _ => build.opt_level(3).debug(false).define("NDEBUG", None),
+        };
+        if cfg!(feature = "highs_asan") {
+            for flag in ASAN_FLAGS {
+                build.flag(flag);
+            }
+        }
+    };
+
+    let mut c_build = cc::Build::new();
+    configure(&mut c_build);
+    let c_objects = c_build.files(&c_sources).compile_intermediates();
+
+    let mut cpp_build = cc::Build::new();
+    configure(&mut cpp_build);
+    cpp_build.cpp(true).std("c++17").cpp_link_stdlib(None);
+    // HiGHS requires C++ exceptions, which are not enabled by default for emscripten.
+    if emscripten {
+        cpp_build.flag("-fexceptions");
+    }
+    cpp_build
+        .files(&cpp_sources)
+        .objects(&c_objects)
+        .compile("highs");
+
+    emit_build_info([config_dir.as_path()], "Bundled", true, Some(build_type));
+    link_static_highs(&target, &dst, &[config_dir, highs_dir], None);
+
+    true
+}
+
+#[cfg(not(feature = "build-cc"))]
+fn build_cc() -> bool {
+    false
+}
+
 #[cfg(feature = "discover")]
 fn discover() -> bool {
     let lib = match pkg_config::Config::new()
@@ -169,6 +910,23 @@ fn discover() -> bool {
     };
 
     generate_bindings(None, lib.include_paths.iter().map(|p| p.as_path()));
+    // Prefer the version of the headers we generated the bindings from.
+    let version = HighsVersion::from_config(lib.include_paths.iter().map(|p| p.as_path()))
+        .or_else(|| HighsVersion::parse(&lib.version))
+        .expect("Couldn't determine the version of the discovered HiGHS");
+    emit_version_config(version);
+    emit_link_metadata(
+        lib.include_paths.iter().map(|p| p.as_path()),
+        lib.link_paths.iter().map(|p| p.as_path()),
+        version,
+        false,
+    );
+    emit_build_info(
+        lib.include_paths.iter().map(|p| p.as_path()),
+        "Discovered",
+        false,
+        None,
+    );
 
     true
 }
@@ -178,7 +936,7 @@ fn discover() -> bool {
     false
 }
 
-#[cfg(feature = "build")]
+#[cfg(any(feature = "build", feature = "build-cc"))]
 fn update_submodules<P: AsRef<Path>>(work_dir: P) {
     use std::process::Command;
 
@@ -195,12 +953,97 @@ fn update_submodules<P: AsRef<Path>>(work_dir: P) {
         .args(args)
         .status();
 
+    // Failures are reported by `highs_source_dir` if the sources are still missing.
     match ret.map(|status| (status.success(), status.code())) {
         Ok((true, _)) => (),
-        Ok((false, Some(c))) => panic!("Command failed with error code {}", c),
-        Ok((false, None)) => panic!("Command got killed"),
-        Err(e) => panic!("Command failed with error: {}", e),
+        Ok((false, Some(c))) => println!("Command failed with error code {}", c),
+        Ok((false, None)) => println!("Command got killed"),
+        Err(e) => println!("Command failed with error: {}", e),
+    }
+}
+
+/// Unpacks a `.tar.gz` archive of the HiGHS sources into `OUT_DIR` and returns the root of
+/// the source tree, which may be nested in a top-level directory of the archive.
+#[cfg(any(feature = "build", feature = "build-cc"))]
+fn unpack_source_archive(archive: &Path) -> PathBuf {
+    use std::process::Command;
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join("highs-src");
+    if out_dir.exists() {
+        std::fs::remove_dir_all(&out_dir).expect("Couldn't clean the HiGHS source directory");
     }
+    std::fs::create_dir_all(&out_dir).expect("Couldn't create the HiGHS source directory");
+
+    let status = Command::new("tar")
+        .arg("-xzf")
+        .arg(archive)
+        .arg("-C")
+        .arg(&out_dir)
+        .status()
+        .unwrap_or_else(|e| panic!("Couldn't run tar to unpack {}: {}", archive.display(), e));
+    if !status.success() {
+        panic!(
+            "Couldn't unpack the HiGHS sources from {}",
+            archive.display()
+        );
+    }
+
+    if out_dir.join("CMakeLists.txt").exists() {
+        return out_dir;
+    }
+    std::fs::read_dir(&out_dir)
+        .expect("Couldn't read the HiGHS source directory")
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .find(|path| path.join("CMakeLists.txt").exists())
+        .unwrap_or_else(|| {
+            panic!(
+                "{} does not contain a HiGHS source tree with a CMakeLists.txt",
+                archive.display()
+            )
+        })
+}
+
+/// Finds the HiGHS source tree to build, in order of preference:
+/// an unpacked tree given by `HIGHS_SOURCE_DIR`, a `.tar.gz` archive given by
+/// `HIGHS_SOURCE_ARCHIVE`, or the bundled `HiGHS` submodule, which is checked out if missing.
+#[cfg(any(feature = "build", feature = "build-cc"))]
+fn highs_source_dir() -> PathBuf {
+    println!("cargo:rerun-if-env-changed=HIGHS_SOURCE_DIR");
+    println!("cargo:rerun-if-env-changed=HIGHS_SOURCE_ARCHIVE");
+
+    if let Some(dir) = env::var_os("HIGHS_SOURCE_DIR") {
+        let dir = PathBuf::from(dir);
+        if !dir.join("CMakeLists.txt").exists() {
+            panic!(
+                "HIGHS_SOURCE_DIR is set to {}, which is not a HiGHS source tree (no CMakeLists.txt found)",
+                dir.display()
+            );
+        }
+        return dir;
+    }
+
+    if let Some(archive) = env::var_os("HIGHS_SOURCE_ARCHIVE") {
+        let archive = PathBuf::from(archive);
+        println!("cargo:rerun-if-changed={}", archive.display());
+        return unpack_source_archive(&archive);
+    }
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let bundled = manifest_dir.join("HiGHS");
+    if !bundled.join("highs").exists() && manifest_dir.join(".git").exists() {
+        update_submodules(&manifest_dir);
+    }
+    if !bundled.join("highs").exists() {
+        panic!(
+            "The HiGHS sources were not found in {}. To build HiGHS, either:
+  - check out the HiGHS submodule, by cloning highs-sys with `git clone --recursive` or running `git submodule update --init`,
+  - set HIGHS_SOURCE_DIR to the path of an unpacked HiGHS source tree,
+  - set HIGHS_SOURCE_ARCHIVE to the path of a .tar.gz archive of the HiGHS sources,
+  - or enable the 'discover' feature to link against an installed HiGHS instead.",
+            bundled.display()
+        );
+    }
+    bundled
 }
 
 fn main() {
@@ -211,30 +1054,29 @@ fn main() {
     if cfg!(all(
         any(
             feature = "highs_release",
+            feature = "highs_debug",
+            feature = "highs_asan",
             feature = "libz",
-            feature = "ninja"
+            feature = "ninja",
+            feature = "highsint64",
+            feature = "hipo"
         ),
-        not(feature = "build")
+        not(any(feature = "build", feature = "build-cc"))
     )) {
         panic!(
-            "You have enabled features that control how HiGHS is built, but have not enabled the 'build' feature.
+            "You have enabled features that control how HiGHS is built, but have not enabled the 'build' or 'build-cc' feature.
 \
-               Thus, your features will never have any effect. Please enable the 'build' feature on highs-sys if you want to build HiGHS or disable the 'libz', 'ninja' and 'highs_release' features."
+               Thus, your features will never have any effect. Please enable the 'build' or 'build-cc' feature on highs-sys if you want to build HiGHS or disable the 'libz', 'ninja', 'highsint64', 'hipo', 'highs_release', 'highs_debug' and 'highs_asan' features."
         );
     }
 
-    #[cfg(feature = "build")]
-    if !Path::new("HiGHS/highs").exists() {
-        update_submodules(env::var("CARGO_MANIFEST_DIR").unwrap());
-    }
-
     if cfg!(feature = "discover") && crt_static {
         println!(
             "cargo::warning=You have enabled Rust's 'crt-static' target feature, but also enabled the 'discover' feature. Discovering a system-installed HiGHS bypasses the bundled build, so highs-sys cannot ensure that HiGHS uses the same MSVC runtime. Please disable 'discover' when using '-C target-feature=+crt-static'."
         );
     }
 
-    if !discover() && !build() {
+    if !discover() && !build_cc() && !build() {
         panic!("Could neither discover nor build HiGHS");
     }
 }
@@ -0,0 +1,32 @@
+// Layout of the HiGHS C API structs as seen by the C compiler for the target.
+// `tests/test_layout.rs` compares them with the layout of the generated Rust types,
+// which also covers targets where bindgen's own layout tests are disabled. The build script
+// only compiles this file with the `layout-probes` feature.
+#include <stddef.h>
+
+#include <interfaces/highs_c_api.h>
+
+#define HIGHS_SYS_PROBE(name, value) const size_t highs_sys_layout_##name = value;
+
+#if HIGHS_VERSION_MAJOR > 1 || HIGHS_VERSION_MINOR >= 6
+HIGHS_SYS_PROBE(HighsCallbackDataOut_size, sizeof(HighsCallbackDataOut))
+HIGHS_SYS_PROBE(HighsCallbackDataOut_align, _Alignof(HighsCallbackDataOut))
+HIGHS_SYS_PROBE(HighsCallbackDataOut_log_type,
+                offsetof(HighsCallbackDataOut, log_type))
+HIGHS_SYS_PROBE(HighsCallbackDataOut_running_time,
+                offsetof(HighsCallbackDataOut, running_time))
+HIGHS_SYS_PROBE(HighsCallbackDataOut_objective_function_value,
+                offsetof(HighsCallbackDataOut, objective_function_value))
+HIGHS_SYS_PROBE(HighsCallbackDataOut_mip_node_count,
+                offsetof(HighsCallbackDataOut, mip_node_count))
+HIGHS_SYS_PROBE(HighsCallbackDataOut_mip_primal_bound,
+                offsetof(HighsCallbackDataOut, mip_primal_bound))
+HIGHS_SYS_PROBE(HighsCallbackDataOut_mip_dual_bound,
+                offsetof(HighsCallbackDataOut, mip_dual_bound))
+HIGHS_SYS_PROBE(HighsCallbackDataOut_mip_gap,
+                offsetof(HighsCallbackDataOut, mip_gap))
+HIGHS_SYS_PROBE(HighsCallbackDataIn_size, sizeof(HighsCallbackDataIn))
+HIGHS_SYS_PROBE(HighsCallbackDataIn_align, _Alignof(HighsCallbackDataIn))
+HIGHS_SYS_PROBE(HighsCallbackDataIn_user_interrupt,
+                offsetof(HighsCallbackDataIn, user_interrupt))
+#endif
@@ -0,0 +1,93 @@
+//! Information about the HiGHS library highs-sys is linked with, for logging and diagnostics.
+
+use std::ffi::CStr;
+use std::fmt;
+
+use crate::{Highs_create, Highs_destroy, Highs_getSizeofHighsInt, Highs_githash, Highs_version};
+
+/// Where the linked HiGHS library comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighsSource {
+    /// Built from the sources bundled with highs-sys, by the `build` or `build-cc` feature.
+    Bundled,
+    /// Taken from the directory given by the `HIGHS_PREBUILT_DIR` environment variable.
+    Prebuilt,
+    /// A system-installed HiGHS found with pkg-config, by the `discover` feature.
+    Discovered,
+}
+
+include!(concat!(env!("OUT_DIR"), "/build_info.rs"));
+
+/// How the linked HiGHS library was built, as returned by [`build_info`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// The version reported by the linked library, e.g. `1.11.0`.
+    pub version: String,
+    /// The git commit the linked library was built from.
+    pub githash: String,
+    /// The date highs-sys built HiGHS, or discovered it, as `YYYY-MM-DD`.
+    pub compilation_date: &'static str,
+    /// The width of `HighsInt` in the linked library, in bits.
+    pub highs_int_bits: usize,
+    /// Whether HiGHS was built with zlib, to read compressed model files.
+    pub zlib: bool,
+    /// Where the linked HiGHS library comes from.
+    pub source: HighsSource,
+    /// Whether HiGHS is linked statically.
+    pub static_link: bool,
+    /// The CMake build type of HiGHS, such as `Release`, if it is known.
+    pub profile: Option<&'static str>,
+}
+
+impl fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HiGHS {} (git {}), {:?} {}, {}-bit HighsInt, zlib {}, built {}",
+            self.version,
+            self.githash,
+            self.source,
+            if self.static_link {
+                "static"
+            } else {
+                "dynamic"
+            },
+            self.highs_int_bits,
+            if self.zlib { "enabled" } else { "disabled" },
+            self.compilation_date,
+        )?;
+        if let Some(profile) = self.profile {
+            write!(f, " ({})", profile)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns how the linked HiGHS library was built. The version, git hash and `HighsInt` width
+/// are queried from the library itself, the rest is recorded by the build script.
+pub fn build_info() -> BuildInfo {
+    let (version, githash, highs_int_size) = unsafe {
+        let highs = Highs_create();
+        let highs_int_size = Highs_getSizeofHighsInt(highs);
+        Highs_destroy(highs);
+        (
+            CStr::from_ptr(Highs_version())
+                .to_string_lossy()
+                .into_owned(),
+            CStr::from_ptr(Highs_githash())
+                .to_string_lossy()
+                .into_owned(),
+            highs_int_size,
+        )
+    };
+    BuildInfo {
+        version,
+        githash,
+        compilation_date: COMPILATION_DATE,
+        highs_int_bits: highs_int_size as usize * 8,
+        zlib: ZLIB,
+        source: SOURCE,
+        static_link: STATIC_LINK,
+        profile: PROFILE,
+    }
+}
@@ -0,0 +1,72 @@
+//! The error type of the safe wrappers around the HiGHS C API.
+
+use std::fmt;
+
+use crate::{HighsInt, STATUS_ERROR};
+
+/// An error returned by the safe wrappers around the HiGHS C API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HighsError {
+    /// A HiGHS C API function returned `STATUS_ERROR`. HiGHS logs the reason, unless its
+    /// `output_flag` option is off.
+    Status {
+        /// The name of the C API function, e.g. `Highs_run`.
+        function: &'static str,
+    },
+    /// A string passed to HiGHS contains a nul byte, so it cannot be passed to C.
+    NulByte(String),
+    /// Another column or row already has this name.
+    DuplicateName(String),
+    /// The arrays of a [`Model`](crate::Model) don't have consistent lengths or indices, so
+    /// HiGHS would read past their end.
+    InvalidModel(String),
+    /// A solution or basis doesn't have as many columns and rows as the model, so HiGHS would
+    /// read past the end of its arrays.
+    DimensionMismatch(String),
+    /// An option or info value has a type that is none of the `kHighs*Type*` constants.
+    UnknownType {
+        /// The name of the option or info value.
+        name: String,
+        /// Its type, as returned by HiGHS.
+        value_type: HighsInt,
+    },
+    /// A matrix format is neither `MATRIX_FORMAT_COLUMN_WISE` nor `MATRIX_FORMAT_ROW_WISE`.
+    InvalidMatrixFormat(HighsInt),
+    /// A thread count is larger than the largest `HighsInt`, so HiGHS can't take it.
+    TooManyThreads(usize),
+}
+
+impl HighsError {
+    /// Turns the status returned by the C API `function` into a result.
+    /// `STATUS_WARNING` is not an error.
+    pub(crate) fn check(function: &'static str, status: HighsInt) -> Result<(), HighsError> {
+        if status == STATUS_ERROR {
+            Err(HighsError::Status { function })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Display for HighsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HighsError::Status { function } => write!(f, "{} returned an error status", function),
+            HighsError::NulByte(string) => write!(f, "{:?} contains a nul byte", string),
+            HighsError::DuplicateName(name) => write!(f, "the name {:?} is already used", name),
+            HighsError::InvalidModel(reason) => write!(f, "invalid model: {}", reason),
+            HighsError::DimensionMismatch(reason) => write!(f, "{}", reason),
+            HighsError::UnknownType { name, value_type } => {
+                write!(f, "{} has unknown type {}", name, value_type)
+            }
+            HighsError::InvalidMatrixFormat(format) => {
+                write!(f, "invalid matrix format {}", format)
+            }
+            HighsError::TooManyThreads(threads) => {
+                write!(f, "{} threads are more than HiGHS supports", threads)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HighsError {}
@@ -0,0 +1,60 @@
+//! Paths of the files that HiGHS reads and writes.
+
+use std::ffi::CString;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An error with the path of a model, solution or options file, before HiGHS gets it.
+#[derive(Debug)]
+pub enum PathError {
+    /// The path is not valid UTF-8 or contains a nul byte, so it can't be passed to HiGHS.
+    Invalid(PathBuf),
+    /// The file couldn't be opened or created.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Invalid(path) => write!(f, "{} can't be passed to HiGHS", path.display()),
+            PathError::Io { path, source } => {
+                write!(f, "couldn't open {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PathError::Io { source, .. } => Some(source),
+            PathError::Invalid(_) => None,
+        }
+    }
+}
+
+impl PathError {
+    pub(crate) fn io(path: &Path) -> impl FnOnce(io::Error) -> PathError + '_ {
+        move |source| PathError::Io {
+            path: path.into(),
+            source,
+        }
+    }
+}
+
+/// Converts `path` for the C API.
+pub(crate) fn c_path(path: &Path) -> Result<CString, PathError> {
+    path.to_str()
+        .and_then(|path| CString::new(path).ok())
+        .ok_or_else(|| PathError::Invalid(path.into()))
+}
+
+/// Converts `path` for the C API, after checking that the file can be opened: HiGHS reports a
+/// missing file like one it can't parse.
+pub(crate) fn c_path_to_read(path: &Path) -> Result<CString, PathError> {
+    let c_path = c_path(path)?;
+    File::open(path).map_err(PathError::io(path))?;
+    Ok(c_path)
+}
@@ -0,0 +1,246 @@
+//! Construction and validation of the Hessian of a quadratic objective.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::highs::c_len;
+use crate::{kHighsHessianFormatTriangular, Highs, HighsError, Highs_passHessian, SparseMatrix};
+
+/// The largest dimension [`Hessian::check_positive_semidefinite`] accepts, as it factors a dense
+/// copy of the matrix.
+pub const MAX_SEMIDEFINITE_CHECK_DIM: usize = 1000;
+
+/// The Hessian `Q` of a quadratic objective `c^T x + 1/2 x^T Q x`, stored as HiGHS expects it:
+/// the lower triangle, column-wise.
+///
+/// Entries given more than once are summed, and entries that are zero are dropped.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Hessian {
+    dim: usize,
+    matrix: SparseMatrix,
+}
+
+/// Why a [`Hessian`] could not be built or checked.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HessianError {
+    /// An entry lies outside of the `dim` by `dim` matrix.
+    IndexOutOfRange { row: usize, col: usize, dim: usize },
+    /// An entry is infinite or NaN.
+    NotFinite { row: usize, col: usize },
+    /// The entries of a full matrix at `(row, col)` and `(col, row)` differ.
+    Asymmetric { row: usize, col: usize },
+    /// The matrix has a direction of negative curvature, so the objective is not convex.
+    NotPositiveSemidefinite,
+    /// The matrix is too large to check for semidefiniteness, see [`MAX_SEMIDEFINITE_CHECK_DIM`].
+    TooLarge { dim: usize },
+}
+
+impl fmt::Display for HessianError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HessianError::IndexOutOfRange { row, col, dim } => write!(
+                f,
+                "Hessian entry ({}, {}) is out of range for dimension {}",
+                row, col, dim
+            ),
+            HessianError::NotFinite { row, col } => {
+                write!(f, "Hessian entry ({}, {}) is not finite", row, col)
+            }
+            HessianError::Asymmetric { row, col } => write!(
+                f,
+                "Hessian entries ({}, {}) and ({}, {}) differ",
+                row, col, col, row
+            ),
+            HessianError::NotPositiveSemidefinite => {
+                write!(f, "the Hessian is not positive semidefinite")
+            }
+            HessianError::TooLarge { dim } => write!(
+                f,
+                "the Hessian of dimension {} is too large to check for semidefiniteness",
+                dim
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HessianError {}
+
+impl Hessian {
+    /// Builds a Hessian from the `(row, col, value)` entries of one triangle, lower or upper,
+    /// or a mix of both: `(i, j)` and `(j, i)` both denote the same pair of symmetric entries.
+    pub fn from_triangular_triplets(
+        dim: usize,
+        triplets: &[(usize, usize, f64)],
+    ) -> Result<Self, HessianError> {
+        let entries = sum_entries(dim, triplets)?;
+        let mut lower = BTreeMap::new();
+        for ((row, col), value) in entries {
+            *lower.entry((row.min(col), row.max(col))).or_insert(0.) += value;
+        }
+        Ok(Self::from_lower(dim, lower))
+    }
+
+    /// Builds a Hessian from the `(row, col, value)` entries of the full symmetric matrix, in
+    /// which every off-diagonal entry appears at both `(i, j)` and `(j, i)`.
+    pub fn from_symmetric_triplets(
+        dim: usize,
+        triplets: &[(usize, usize, f64)],
+    ) -> Result<Self, HessianError> {
+        let entries = sum_entries(dim, triplets)?;
+        let mut lower = BTreeMap::new();
+        for (&(row, col), &value) in &entries {
+            let mirror = entries.get(&(col, row)).copied().unwrap_or(0.);
+            let tolerance = 1e-9 * value.abs().max(mirror.abs()).max(1.);
+            if (value - mirror).abs() > tolerance {
+                return Err(HessianError::Asymmetric { row, col });
+            }
+            if row >= col {
+                lower.insert((col, row), value);
+            }
+        }
+        Ok(Self::from_lower(dim, lower))
+    }
+
+    /// Builds the column-wise storage from lower triangle entries keyed by `(col, row)`,
+    /// which a `BTreeMap` iterates column by column.
+    fn from_lower(dim: usize, lower: BTreeMap<(usize, usize), f64>) -> Self {
+        let mut matrix = SparseMatrix {
+            start: Vec::with_capacity(dim),
+            index: Vec::new(),
+            value: Vec::new(),
+        };
+        let mut entries = lower
+            .into_iter()
+            .filter(|&(_, value)| value != 0.)
+            .peekable();
+        for col in 0..dim {
+            matrix.start.push(c_len(matrix.value.len()));
+            while let Some(((_, row), value)) = entries.next_if(|&((c, _), _)| c == col) {
+                matrix.index.push(c_len(row));
+                matrix.value.push(value);
+            }
+        }
+        Hessian { dim, matrix }
+    }
+
+    /// The number of rows and columns, which is the number of columns of the model.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// The number of nonzeros in the lower triangle.
+    pub fn num_nz(&self) -> usize {
+        self.matrix.num_nz()
+    }
+
+    /// The lower triangle, column-wise, as in [`Model::hessian`](crate::Model::hessian).
+    pub fn matrix(&self) -> &SparseMatrix {
+        &self.matrix
+    }
+
+    /// Checks that the Hessian is positive semidefinite, which HiGHS requires of a minimized
+    /// quadratic objective, up to a relative `tolerance` such as `1e-9`.
+    ///
+    /// This factors a dense copy of the matrix, so only Hessians up to
+    /// [`MAX_SEMIDEFINITE_CHECK_DIM`] can be checked.
+    pub fn check_positive_semidefinite(&self, tolerance: f64) -> Result<(), HessianError> {
+        let n = self.dim;
+        if n > MAX_SEMIDEFINITE_CHECK_DIM {
+            return Err(HessianError::TooLarge { dim: n });
+        }
+        // A dense copy of the full matrix, row-major.
+        let mut a = vec![0.; n * n];
+        for (col, &start) in self.matrix.start.iter().enumerate() {
+            let end = self
+                .matrix
+                .start
+                .get(col + 1)
+                .map_or(self.num_nz(), |&end| end as usize);
+            for k in start as usize..end {
+                let row = self.matrix.index[k] as usize;
+                a[row * n + col] = self.matrix.value[k];
+                a[col * n + row] = self.matrix.value[k];
+            }
+        }
+        let scale = self.matrix.value.iter().fold(1f64, |m, v| m.max(v.abs()));
+        let tolerance = tolerance * scale;
+
+        // Cholesky factorization with diagonal pivoting: a positive semidefinite matrix only
+        // runs out of positive pivots once the remaining Schur complement is zero.
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|&i, &j| a[i * n + i].total_cmp(&a[j * n + j]))
+                .unwrap();
+            let pivot = a[pivot_row * n + pivot_row];
+            if pivot < -tolerance {
+                return Err(HessianError::NotPositiveSemidefinite);
+            }
+            if pivot <= tolerance {
+                let remainder_is_zero =
+                    (k..n).all(|i| (k..n).all(|j| a[i * n + j].abs() <= tolerance));
+                return if remainder_is_zero {
+                    Ok(())
+                } else {
+                    Err(HessianError::NotPositiveSemidefinite)
+                };
+            }
+            // Move the pivot to position k, permuting rows and columns alike.
+            for j in 0..n {
+                a.swap(k * n + j, pivot_row * n + j);
+            }
+            for i in 0..n {
+                a.swap(i * n + k, i * n + pivot_row);
+            }
+            for i in k + 1..n {
+                let factor = a[i * n + k] / pivot;
+                for j in k + 1..n {
+                    a[i * n + j] -= factor * a[k * n + j];
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<Hessian> for SparseMatrix {
+    fn from(hessian: Hessian) -> Self {
+        hessian.matrix
+    }
+}
+
+/// Sums the entries given more than once, after checking that they are valid.
+fn sum_entries(
+    dim: usize,
+    triplets: &[(usize, usize, f64)],
+) -> Result<BTreeMap<(usize, usize), f64>, HessianError> {
+    let mut entries = BTreeMap::new();
+    for &(row, col, value) in triplets {
+        if row >= dim || col >= dim {
+            return Err(HessianError::IndexOutOfRange { row, col, dim });
+        }
+        if !value.is_finite() {
+            return Err(HessianError::NotFinite { row, col });
+        }
+        *entries.entry((row, col)).or_insert(0.) += value;
+    }
+    Ok(entries)
+}
+
+impl Highs {
+    /// Sets the Hessian of the objective, whose dimension must be the number of columns.
+    pub fn pass_hessian(&mut self, hessian: &Hessian) -> Result<(), HighsError> {
+        let matrix = &hessian.matrix;
+        let status = unsafe {
+            Highs_passHessian(
+                self.as_mut_ptr(),
+                c_len(hessian.dim),
+                c_len(hessian.num_nz()),
+                kHighsHessianFormatTriangular,
+                matrix.start.as_ptr(),
+                matrix.index.as_ptr(),
+                matrix.value.as_ptr(),
+            )
+        };
+        HighsError::check("Highs_passHessian", status)
+    }
+}
@@ -0,0 +1,220 @@
+//! A safe, owning handle to a HiGHS solver instance.
+
+use std::convert::TryFrom;
+use std::ffi::{c_void, CStr, CString};
+use std::os::raw::c_char;
+use std::sync::Once;
+
+use crate::scheduler::{configured_threads, threads_option, Parallel};
+use crate::{
+    check_highs_int_size, kHighsMaximumStringLength, HighsError, HighsInt, Highs_create,
+    Highs_destroy, Highs_getBoolOptionValue, Highs_getDoubleOptionValue, Highs_getIntOptionValue,
+    Highs_getModelStatus, Highs_getObjectiveValue, Highs_getStringOptionValue, Highs_run,
+    Highs_setBoolOptionValue, Highs_setDoubleOptionValue, Highs_setIntOptionValue,
+    Highs_setStringOptionValue, MATRIX_FORMAT_COLUMN_WISE,
+};
+
+/// Converts `string` for the C API.
+pub(crate) fn c_string(string: &str) -> Result<CString, HighsError> {
+    CString::new(string).map_err(|_| HighsError::NulByte(string.to_string()))
+}
+
+/// Converts a length or index for the C API, panicking if it does not fit in a `HighsInt`.
+pub(crate) fn c_len(len: usize) -> HighsInt {
+    HighsInt::try_from(len).expect("length exceeds the range of HighsInt")
+}
+
+/// Converts a length or index returned by the C API.
+pub(crate) fn rust_len(len: HighsInt) -> usize {
+    usize::try_from(len).expect("HiGHS returned a negative length")
+}
+
+/// A HiGHS solver instance, created with `Highs_create` and destroyed when dropped.
+///
+/// The safe methods cover common operations. For everything else, pass [`Highs::as_ptr`] or
+/// [`Highs::as_mut_ptr`] to the C API functions of this crate.
+#[derive(Debug)]
+pub struct Highs {
+    ptr: *mut c_void,
+}
+
+/// Runs [`check_highs_int_size`] before the first instance is created.
+static HIGHS_INT_SIZE_CHECK: Once = Once::new();
+
+// A HiGHS instance has no thread affinity, it must only not be used by two threads at once.
+unsafe impl Send for Highs {}
+
+impl Highs {
+    /// Creates an instance with an empty model.
+    ///
+    /// If [`set_global_scheduler_threads`](crate::set_global_scheduler_threads) was called,
+    /// its thread count is set as the `threads` option.
+    ///
+    /// # Panics
+    ///
+    /// The first call panics if the linked library has another `HighsInt` width than the
+    /// bindings, see [`check_highs_int_size`](crate::check_highs_int_size).
+    pub fn new() -> Self {
+        HIGHS_INT_SIZE_CHECK.call_once(check_highs_int_size);
+        let mut highs = Highs {
+            ptr: unsafe { Highs_create() },
+        };
+        assert!(!highs.ptr.is_null(), "Highs_create returned null");
+        if let Some(threads) = configured_threads() {
+            highs
+                .set_threads(threads)
+                .expect("Couldn't set the threads option");
+        }
+        highs
+    }
+
+    /// The pointer to pass to C API functions that take a `const void*`.
+    pub fn as_ptr(&self) -> *const c_void {
+        self.ptr
+    }
+
+    /// The pointer to pass to C API functions that take a `void*`.
+    pub fn as_mut_ptr(&mut self) -> *mut c_void {
+        self.ptr
+    }
+
+    /// Sets the bool option `name`, e.g. `output_flag`.
+    pub fn set_bool_option(&mut self, name: &str, value: bool) -> Result<(), HighsError> {
+        let name = c_string(name)?;
+        let status = unsafe { Highs_setBoolOptionValue(self.ptr, name.as_ptr(), value.into()) };
+        HighsError::check("Highs_setBoolOptionValue", status)
+    }
+
+    /// Sets the integer option `name`, e.g. `threads`.
+    pub fn set_int_option(&mut self, name: &str, value: HighsInt) -> Result<(), HighsError> {
+        let name = c_string(name)?;
+        let status = unsafe { Highs_setIntOptionValue(self.ptr, name.as_ptr(), value) };
+        HighsError::check("Highs_setIntOptionValue", status)
+    }
+
+    /// Sets the floating point option `name`, e.g. `time_limit`.
+    pub fn set_double_option(&mut self, name: &str, value: f64) -> Result<(), HighsError> {
+        let name = c_string(name)?;
+        let status = unsafe { Highs_setDoubleOptionValue(self.ptr, name.as_ptr(), value) };
+        HighsError::check("Highs_setDoubleOptionValue", status)
+    }
+
+    /// Sets the string option `name`, e.g. `solver`.
+    pub fn set_string_option(&mut self, name: &str, value: &str) -> Result<(), HighsError> {
+        let name = c_string(name)?;
+        let value = c_string(value)?;
+        let status = unsafe { Highs_setStringOptionValue(self.ptr, name.as_ptr(), value.as_ptr()) };
+        HighsError::check("Highs_setStringOptionValue", status)
+    }
+
+    /// Returns the value of the bool option `name`.
+    pub fn get_bool_option(&self, name: &str) -> Result<bool, HighsError> {
+        let name = c_string(name)?;
+        let mut value = 0;
+        let status = unsafe { Highs_getBoolOptionValue(self.ptr, name.as_ptr(), &mut value) };
+        HighsError::check("Highs_getBoolOptionValue", status)?;
+        Ok(value != 0)
+    }
+
+    /// Returns the value of the integer option `name`.
+    pub fn get_int_option(&self, name: &str) -> Result<HighsInt, HighsError> {
+        let name = c_string(name)?;
+        let mut value = 0;
+        let status = unsafe { Highs_getIntOptionValue(self.ptr, name.as_ptr(), &mut value) };
+        HighsError::check("Highs_getIntOptionValue", status)?;
+        Ok(value)
+    }
+
+    /// Returns the value of the floating point option `name`.
+    pub fn get_double_option(&self, name: &str) -> Result<f64, HighsError> {
+        let name = c_string(name)?;
+        let mut value = 0.;
+        let status = unsafe { Highs_getDoubleOptionValue(self.ptr, name.as_ptr(), &mut value) };
+        HighsError::check("Highs_getDoubleOptionValue", status)?;
+        Ok(value)
+    }
+
+    /// Returns the value of the string option `name`.
+    pub fn get_string_option(&self, name: &str) -> Result<String, HighsError> {
+        let name = c_string(name)?;
+        let mut value = vec![0 as c_char; kHighsMaximumStringLength as usize];
+        let status =
+            unsafe { Highs_getStringOptionValue(self.ptr, name.as_ptr(), value.as_mut_ptr()) };
+        HighsError::check("Highs_getStringOptionValue", status)?;
+        let value = unsafe { CStr::from_ptr(value.as_ptr()) };
+        Ok(value.to_string_lossy().into_owned())
+    }
+
+    /// Sets the `threads` option: the number of threads the HiGHS scheduler runs with.
+    ///
+    /// The scheduler is shared by all instances that run on a thread, and runs fail if their
+    /// `threads` option disagrees with it, see [`set_global_scheduler_threads`](crate::set_global_scheduler_threads).
+    /// Returns [`HighsError::TooManyThreads`] if `threads` doesn't fit in a `HighsInt`.
+    pub fn set_threads(&mut self, threads: usize) -> Result<(), HighsError> {
+        self.set_int_option("threads", threads_option(threads)?)
+    }
+
+    /// Sets the `parallel` option, which decides whether HiGHS uses its parallel algorithms.
+    pub fn set_parallel(&mut self, parallel: Parallel) -> Result<(), HighsError> {
+        self.set_string_option("parallel", parallel.as_str())
+    }
+
+    /// Solves the model with `Highs_run`. Whether a solution was found is told by
+    /// [`Highs::model_status`], such as `MODEL_STATUS_OPTIMAL`.
+    pub fn run(&mut self) -> Result<(), HighsError> {
+        let status = unsafe { Highs_run(self.ptr) };
+        HighsError::check("Highs_run", status)
+    }
+
+    /// The status of the model after [`Highs::run`], one of the `MODEL_STATUS_*` constants.
+    pub fn model_status(&self) -> HighsInt {
+        unsafe { Highs_getModelStatus(self.ptr) }
+    }
+
+    /// The objective function value of the current solution.
+    pub fn objective_value(&self) -> f64 {
+        unsafe { Highs_getObjectiveValue(self.ptr) }
+    }
+
+    /// Copies this instance into a new one: the options, the model, and the solution and
+    /// basis if there are some. Modifying the copy leaves this instance unchanged.
+    ///
+    /// The model status and info values are not copied, as HiGHS only sets them by running.
+    pub fn try_clone(&self) -> Result<Highs, HighsError> {
+        let mut clone = Highs::new();
+        // Copy the options first, since some affect how the model is read, like `infinite_bound`.
+        for name in self.option_names()? {
+            let value = self.get_option(&name)?;
+            if clone.get_option(&name)? != value {
+                clone.set_option(&name, &value)?;
+            }
+        }
+        clone.pass_model(&self.get_model(MATRIX_FORMAT_COLUMN_WISE)?)?;
+        if self.has_solution() {
+            clone.set_solution(&self.get_solution()?)?;
+        }
+        if self.has_basis() {
+            clone.set_basis(&self.get_basis()?)?;
+        }
+        Ok(clone)
+    }
+}
+
+impl Clone for Highs {
+    /// Copies the instance with [`Highs::try_clone`], panicking if HiGHS reports an error.
+    fn clone(&self) -> Self {
+        self.try_clone().expect("Couldn't copy the HiGHS instance")
+    }
+}
+
+impl Default for Highs {
+    fn default() -> Self {
+        Highs::new()
+    }
+}
+
+impl Drop for Highs {
+    fn drop(&mut self) {
+        unsafe { Highs_destroy(self.ptr) }
+    }
+}
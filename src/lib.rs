@@ -2,7 +2,48 @@
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 
+// HiGHS calls into zlib to read compressed model files; libz-sys links it.
+#[cfg(feature = "libz")]
+extern crate libz_sys;
+
 include!(concat!(env!("OUT_DIR"), "/c_bindings.rs"));
+include!(concat!(env!("OUT_DIR"), "/highs_version.rs"));
+
+mod build_info;
+mod error;
+mod file_path;
+mod hessian;
+mod highs;
+mod lp;
+mod model;
+mod model_file;
+mod modelling;
+mod mps;
+mod names;
+mod options;
+mod options_file;
+mod scheduler;
+#[cfg(feature = "serde")]
+mod serde_infinity;
+mod solution;
+mod solution_file;
+
+pub use build_info::{build_info, BuildInfo, HighsSource};
+pub use error::HighsError;
+pub use file_path::PathError;
+pub use hessian::{Hessian, HessianError, MAX_SEMIDEFINITE_CHECK_DIM};
+pub use highs::Highs;
+pub use model::{Model, SparseMatrix};
+pub use model_file::{ModelFileError, ModelFormat};
+pub use modelling::{Bounds, Constr, LinExpr, Var};
+pub use mps::{MpsError, MpsErrorKind, MpsFormat};
+pub use options::{OptionValue, OptionsEnvError};
+pub use options_file::OptionsFileError;
+pub use scheduler::{
+    global_scheduler_threads, reset_global_scheduler, set_global_scheduler_threads, Parallel,
+};
+pub use solution::{Basis, InfoValue, Solution};
+pub use solution_file::SolutionFileError;
 
 pub const MODEL_STATUS_NOTSET: HighsInt = 0;
 pub const MODEL_STATUS_LOAD_ERROR: HighsInt = 1;
@@ -49,3 +90,54 @@ pub const VAR_TYPE_INTEGER: HighsInt = 1;
 pub const VAR_TYPE_SEMI_CONTINUOUS: HighsInt = 2;
 pub const VAR_TYPE_SEMI_INTEGER: HighsInt = 3;
 pub const VAR_TYPE_IMPLICIT_INTEGER: HighsInt = 4;
+
+/// Checks that the HiGHS library linked at runtime has the same version as the headers
+/// the bindings were generated from (see [`HIGHS_VERSION_MAJOR`] and friends).
+///
+/// This can only differ when HiGHS is linked dynamically, for instance with the `discover`
+/// feature. On a mismatch, a warning is printed to the standard error and `false` is returned.
+pub fn check_linked_version() -> bool {
+    let linked = unsafe {
+        (
+            Highs_versionMajor(),
+            Highs_versionMinor(),
+            Highs_versionPatch(),
+        )
+    };
+    let headers = (
+        HIGHS_VERSION_MAJOR,
+        HIGHS_VERSION_MINOR,
+        HIGHS_VERSION_PATCH,
+    );
+    if linked != headers {
+        eprintln!(
+            "warning: highs-sys was compiled against HiGHS {}.{}.{}, but HiGHS {}.{}.{} is linked",
+            headers.0, headers.1, headers.2, linked.0, linked.1, linked.2
+        );
+    }
+    linked == headers
+}
+
+/// Panics if the HiGHS library linked at runtime uses a different `HighsInt` width than the
+/// headers the bindings were generated from.
+///
+/// With the `build` feature, the width is controlled by the `highsint64` feature and always
+/// matches. A system-installed HiGHS found with `discover` may however have been built with
+/// a different `HIGHSINT64` setting than its headers, and passing indices through the C API
+/// would then corrupt memory. [`Highs::new`] runs this check before creating the first
+/// instance; programs that only use the C API should call it themselves.
+pub fn check_highs_int_size() {
+    let linked = unsafe {
+        let highs = Highs_create();
+        let size = Highs_getSizeofHighsInt(highs);
+        Highs_destroy(highs);
+        size
+    };
+    assert_eq!(
+        linked as usize,
+        std::mem::size_of::<HighsInt>(),
+        "The linked HiGHS library uses a {}-byte HighsInt, but highs-sys was compiled for a {}-byte HighsInt",
+        linked,
+        std::mem::size_of::<HighsInt>()
+    );
+}
@@ -0,0 +1,227 @@
+//! A writer of CPLEX LP files for [`Model`], to read models while debugging them.
+
+use std::borrow::Cow;
+use std::fmt::{self, Write as _};
+
+use crate::model::vectors;
+use crate::{
+    Model, OBJECTIVE_SENSE_MAXIMIZE, VAR_TYPE_CONTINUOUS, VAR_TYPE_INTEGER,
+    VAR_TYPE_SEMI_CONTINUOUS, VAR_TYPE_SEMI_INTEGER,
+};
+
+/// Lines are wrapped after this many characters, since LP readers may limit their length.
+const LINE_LENGTH: usize = 72;
+
+/// Words that LP readers take for keywords rather than names, in lowercase.
+const KEYWORDS: &[&str] = &[
+    "bin", "binaries", "binary", "bound", "bounds", "end", "free", "gen", "general", "generals",
+    "inf", "infinity", "max", "maximize", "maximum", "min", "minimize", "minimum", "semi", "semis",
+    "sos", "st", "subject", "such",
+];
+
+/// Turns `name` into a valid LP name: other characters than letters, digits and
+/// `!#$%&;?@_.{}|~` are replaced with underscores, and an underscore is prepended to names that
+/// would be read as a number or a keyword.
+fn lp_name(name: &str) -> Cow<'_, str> {
+    let valid_char = |c: char| c.is_ascii_alphanumeric() || "!#$%&;?@_.{}|~".contains(c);
+    let mut chars = name.chars();
+    let starts_like_number = match (chars.next(), chars.next()) {
+        (Some(first), _) if first.is_ascii_digit() || first == '.' => true,
+        (Some('e' | 'E'), Some(second)) => second.is_ascii_digit(),
+        _ => false,
+    };
+    let is_keyword = KEYWORDS.contains(&name.to_ascii_lowercase().as_str());
+    if name.chars().all(valid_char) && !starts_like_number && !is_keyword {
+        return Cow::Borrowed(name);
+    }
+    let mut sanitized = String::with_capacity(name.len() + 1);
+    if starts_like_number || is_keyword {
+        sanitized.push('_');
+    }
+    sanitized.extend(name.chars().map(|c| if valid_char(c) { c } else { '_' }));
+    Cow::Owned(sanitized)
+}
+
+/// Writes the terms of a linear or quadratic expression, with their signs.
+struct Terms<'a> {
+    out: &'a mut String,
+    first: bool,
+}
+
+impl<'a> Terms<'a> {
+    fn new(out: &'a mut String) -> Self {
+        Terms { out, first: true }
+    }
+
+    fn add(&mut self, coefficient: f64, term: fmt::Arguments<'_>) -> fmt::Result {
+        self.add_constant(coefficient)?;
+        write!(self.out, " {}", term)
+    }
+
+    fn add_constant(&mut self, value: f64) -> fmt::Result {
+        let line_start = self.out.rfind('\n').map_or(0, |i| i + 1);
+        if self.out.len() - line_start > LINE_LENGTH {
+            self.out.push_str("\n  ");
+        }
+        if self.first {
+            write!(self.out, " {:?}", value)?;
+        } else if value < 0. {
+            write!(self.out, " - {:?}", -value)?;
+        } else {
+            write!(self.out, " + {:?}", value)?;
+        }
+        self.first = false;
+        Ok(())
+    }
+
+    /// Starts a new expression, within brackets for example.
+    fn restart(&mut self) {
+        self.first = true;
+    }
+}
+
+impl Model {
+    /// Writes the model in CPLEX LP format, naming unnamed columns `c0`, `c1`... and unnamed
+    /// rows `r0`, `r1`..., like [`Model::to_mps`]. Names that are not valid in LP files, with
+    /// spaces or operators for instance, have their invalid characters replaced with
+    /// underscores, and names that look like numbers or keywords are prefixed with one.
+    ///
+    /// Integer columns are listed in the General section, or in the Binary section if their
+    /// bounds are 0 and 1. Semi-continuous and semi-integer columns are listed in the
+    /// Semi-continuous section, and semi-integer columns in the General section too.
+    ///
+    /// Empty rows are written with a zero coefficient for the first column. Free rows are left
+    /// out, as are all rows of a model without columns, since the LP format has neither
+    /// constraints without bounds nor constraints without variables.
+    pub fn to_lp(&self) -> String {
+        let mut lp = String::new();
+        self.write_lp(&mut lp)
+            .expect("writing to a String cannot fail");
+        lp
+    }
+
+    fn write_lp(&self, out: &mut String) -> fmt::Result {
+        let integrality = |j: usize| {
+            self.integrality
+                .get(j)
+                .copied()
+                .unwrap_or(VAR_TYPE_CONTINUOUS)
+        };
+        let is_binary = |j: usize| {
+            integrality(j) == VAR_TYPE_INTEGER && self.col_lower[j] == 0. && self.col_upper[j] == 1.
+        };
+
+        if self.sense == OBJECTIVE_SENSE_MAXIMIZE {
+            writeln!(out, "Maximize")?;
+        } else {
+            writeln!(out, "Minimize")?;
+        }
+        let labels = self.labels(lp_name);
+        write!(out, " {}:", labels.objective)?;
+        let mut terms = Terms::new(out);
+        // Every column is in the objective, even with a zero cost, so that readers number the
+        // columns in the same order.
+        for (j, &cost) in self.col_cost.iter().enumerate() {
+            terms.add(cost, format_args!("{}", labels.cols[j]))?;
+        }
+        if self.offset != 0. {
+            terms.add_constant(self.offset)?;
+        }
+        if self.hessian.num_nz() > 0 {
+            // The objective has the term x'Qx / 2, in which each entry below the diagonal
+            // appears twice.
+            write!(terms.out, " + [")?;
+            terms.restart();
+            for (j, entries) in vectors(&self.hessian).iter().enumerate() {
+                for &(i, value) in entries {
+                    if i == j {
+                        terms.add(value, format_args!("{} ^ 2", labels.cols[j]))?;
+                    } else {
+                        let (first, second) = (&labels.cols[j], &labels.cols[i]);
+                        terms.add(2. * value, format_args!("{} * {}", first, second))?;
+                    }
+                }
+            }
+            write!(terms.out, " ] / 2")?;
+        }
+        writeln!(out)?;
+
+        writeln!(out, "Subject To")?;
+        // Rows need a column, even with a zero coefficient, so they are left out without columns.
+        let rows = if self.num_col() > 0 {
+            self.row_entries()
+        } else {
+            Vec::new()
+        };
+        for (i, entries) in rows.iter().enumerate() {
+            let (lower, upper) = (self.row_lower[i], self.row_upper[i]);
+            if lower == f64::NEG_INFINITY && upper == f64::INFINITY {
+                // The LP format has no free rows, which constrain nothing anyway.
+                continue;
+            }
+            write!(out, " {}:", labels.rows[i])?;
+            if lower > f64::NEG_INFINITY && upper < f64::INFINITY && lower != upper {
+                write!(out, " {:?} <=", lower)?;
+            }
+            let mut terms = Terms::new(out);
+            for &(j, value) in entries {
+                terms.add(value, format_args!("{}", labels.cols[j]))?;
+            }
+            if entries.is_empty() {
+                terms.add(0., format_args!("{}", labels.cols[0]))?;
+            }
+            if lower == upper {
+                writeln!(out, " = {:?}", lower)?;
+            } else if upper < f64::INFINITY {
+                writeln!(out, " <= {:?}", upper)?;
+            } else {
+                writeln!(out, " >= {:?}", lower)?;
+            }
+        }
+
+        writeln!(out, "Bounds")?;
+        for j in 0..self.num_col() {
+            let col = &labels.cols[j];
+            let (lower, upper) = (self.col_lower[j], self.col_upper[j]);
+            if is_binary(j) {
+                continue;
+            } else if lower == upper {
+                writeln!(out, " {} = {:?}", col, lower)?;
+            } else if lower == f64::NEG_INFINITY && upper == f64::INFINITY {
+                writeln!(out, " {} free", col)?;
+            } else if upper < f64::INFINITY {
+                writeln!(out, " {:?} <= {} <= {:?}", lower, col, upper)?;
+            } else if lower != 0. {
+                writeln!(out, " {} >= {:?}", col, lower)?;
+            }
+        }
+
+        let general =
+            |j| matches!(integrality(j), VAR_TYPE_INTEGER | VAR_TYPE_SEMI_INTEGER) && !is_binary(j);
+        let semi = |j| {
+            matches!(
+                integrality(j),
+                VAR_TYPE_SEMI_CONTINUOUS | VAR_TYPE_SEMI_INTEGER
+            )
+        };
+        let sections: [(&str, &dyn Fn(usize) -> bool); 3] = [
+            ("General", &general),
+            ("Binary", &is_binary),
+            ("Semi-continuous", &semi),
+        ];
+        for (name, is_in_section) in &sections {
+            let columns: Vec<usize> = (0..self.num_col()).filter(|&j| is_in_section(j)).collect();
+            if columns.is_empty() {
+                continue;
+            }
+            writeln!(out, "{}", name)?;
+            for chunk in columns.chunks(10) {
+                for j in chunk {
+                    write!(out, " {}", labels.cols[*j])?;
+                }
+                writeln!(out)?;
+            }
+        }
+        writeln!(out, "End")
+    }
+}
@@ -0,0 +1,520 @@
+//! An owned copy of the model of a HiGHS instance.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::ptr::{null, null_mut};
+
+use crate::highs::{c_len, rust_len};
+use crate::names::check_unique_names;
+use crate::{
+    kHighsHessianFormatTriangular, Highs, HighsError, HighsInt, Highs_getModel, Highs_passModel,
+    MATRIX_FORMAT_COLUMN_WISE, MATRIX_FORMAT_ROW_WISE, OBJECTIVE_SENSE_MINIMIZE,
+    VAR_TYPE_CONTINUOUS,
+};
+
+/// A sparse matrix in the compressed format of the HiGHS C API.
+///
+/// Vector `i` (a column or a row, depending on the format) has its indices in
+/// `index[start[i]..start[i + 1]]` and its values at the same positions of `value`. Like in the
+/// C API, `start` has one entry per vector and no final entry for the number of nonzeros.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SparseMatrix {
+    pub start: Vec<HighsInt>,
+    pub index: Vec<HighsInt>,
+    pub value: Vec<f64>,
+}
+
+impl SparseMatrix {
+    /// The number of nonzeros.
+    pub fn num_nz(&self) -> usize {
+        self.value.len()
+    }
+}
+
+/// A complete HiGHS model: a linear or quadratic program, possibly with integer variables.
+///
+/// [`Highs::get_model`] extracts it from an instance and [`Highs::pass_model`] loads it into one.
+/// Infinite bounds are given by `Highs_getInfinity`. With the `serde` feature, infinite bounds,
+/// costs and offsets are serialized as ±1e30, and values at least as large are deserialized as
+/// infinite. Deserialized models are checked with [`Model::validate`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedModel")
+)]
+pub struct Model {
+    /// `OBJECTIVE_SENSE_MINIMIZE` or `OBJECTIVE_SENSE_MAXIMIZE`.
+    pub sense: HighsInt,
+    /// The constant term of the objective.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::value"))]
+    pub offset: f64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::vec"))]
+    pub col_cost: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::vec"))]
+    pub col_lower: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::vec"))]
+    pub col_upper: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::vec"))]
+    pub row_lower: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::vec"))]
+    pub row_upper: Vec<f64>,
+    /// `MATRIX_FORMAT_COLUMN_WISE` or `MATRIX_FORMAT_ROW_WISE`.
+    pub a_format: HighsInt,
+    /// The constraint matrix, in the compressed format given by `a_format`.
+    pub a_matrix: SparseMatrix,
+    /// The lower triangle of the Hessian of a quadratic objective, column-wise; empty for an LP.
+    /// See [`Hessian`](crate::Hessian) to build one.
+    pub hessian: SparseMatrix,
+    /// The `VAR_TYPE_*` of each column; empty if all columns are continuous.
+    pub integrality: Vec<HighsInt>,
+    /// The name of each column, or empty if no column has a name. Names must be unique, and an
+    /// empty name leaves its column unnamed.
+    pub col_names: Vec<String>,
+    /// The name of each row, like `col_names`.
+    pub row_names: Vec<String>,
+}
+
+/// The fields of a [`Model`] before [`Model::validate`] checks them, to deserialize it.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedModel {
+    sense: HighsInt,
+    #[serde(with = "crate::serde_infinity::value")]
+    offset: f64,
+    #[serde(with = "crate::serde_infinity::vec")]
+    col_cost: Vec<f64>,
+    #[serde(with = "crate::serde_infinity::vec")]
+    col_lower: Vec<f64>,
+    #[serde(with = "crate::serde_infinity::vec")]
+    col_upper: Vec<f64>,
+    #[serde(with = "crate::serde_infinity::vec")]
+    row_lower: Vec<f64>,
+    #[serde(with = "crate::serde_infinity::vec")]
+    row_upper: Vec<f64>,
+    a_format: HighsInt,
+    a_matrix: SparseMatrix,
+    hessian: SparseMatrix,
+    integrality: Vec<HighsInt>,
+    // Models serialized before names were added have none.
+    #[serde(default)]
+    col_names: Vec<String>,
+    #[serde(default)]
+    row_names: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<UncheckedModel> for Model {
+    type Error = HighsError;
+
+    fn try_from(unchecked: UncheckedModel) -> Result<Model, HighsError> {
+        let model = Model {
+            sense: unchecked.sense,
+            offset: unchecked.offset,
+            col_cost: unchecked.col_cost,
+            col_lower: unchecked.col_lower,
+            col_upper: unchecked.col_upper,
+            row_lower: unchecked.row_lower,
+            row_upper: unchecked.row_upper,
+            a_format: unchecked.a_format,
+            a_matrix: unchecked.a_matrix,
+            hessian: unchecked.hessian,
+            integrality: unchecked.integrality,
+            col_names: unchecked.col_names,
+            row_names: unchecked.row_names,
+        };
+        model.validate()?;
+        Ok(model)
+    }
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Model {
+            sense: OBJECTIVE_SENSE_MINIMIZE,
+            offset: 0.,
+            col_cost: Vec::new(),
+            col_lower: Vec::new(),
+            col_upper: Vec::new(),
+            row_lower: Vec::new(),
+            row_upper: Vec::new(),
+            a_format: MATRIX_FORMAT_COLUMN_WISE,
+            a_matrix: SparseMatrix::default(),
+            hessian: SparseMatrix::default(),
+            integrality: Vec::new(),
+            col_names: Vec::new(),
+            row_names: Vec::new(),
+        }
+    }
+}
+
+impl Model {
+    /// The number of columns, i.e. variables.
+    pub fn num_col(&self) -> usize {
+        self.col_cost.len()
+    }
+
+    /// The number of rows, i.e. constraints.
+    pub fn num_row(&self) -> usize {
+        self.row_lower.len()
+    }
+
+    /// Checks that the lengths of the arrays match the number of columns, rows and nonzeros,
+    /// that the matrices index existing columns and rows, and that the names are unique.
+    ///
+    /// [`Highs::pass_model`] calls this before passing the arrays to HiGHS, which would
+    /// otherwise read past their end.
+    pub fn validate(&self) -> Result<(), HighsError> {
+        let (num_col, num_row) = (self.num_col(), self.num_row());
+        let expect_len = |what: &str, len: usize, expected: usize| {
+            if len == expected {
+                Ok(())
+            } else {
+                Err(HighsError::InvalidModel(format!(
+                    "{} has {} entries instead of {}",
+                    what, len, expected
+                )))
+            }
+        };
+        expect_len("col_lower", self.col_lower.len(), num_col)?;
+        expect_len("col_upper", self.col_upper.len(), num_col)?;
+        expect_len("row_upper", self.row_upper.len(), num_row)?;
+        if !self.integrality.is_empty() {
+            expect_len("integrality", self.integrality.len(), num_col)?;
+        }
+        if !self.col_names.is_empty() {
+            expect_len("col_names", self.col_names.len(), num_col)?;
+        }
+        if !self.row_names.is_empty() {
+            expect_len("row_names", self.row_names.len(), num_row)?;
+        }
+        let (num_vec, num_index) = match self.a_format {
+            MATRIX_FORMAT_COLUMN_WISE => (num_col, num_row),
+            MATRIX_FORMAT_ROW_WISE => (num_row, num_col),
+            a_format => {
+                return Err(HighsError::InvalidModel(format!(
+                    "invalid matrix format {}",
+                    a_format
+                )))
+            }
+        };
+        check_matrix("a_matrix", &self.a_matrix, num_vec, num_index)?;
+        check_matrix("hessian", &self.hessian, num_col, num_col)?;
+        check_unique_names(&self.col_names)?;
+        check_unique_names(&self.row_names)
+    }
+
+    /// The names of the columns, rows and objective in model files, whose format may need to
+    /// `sanitize` some names.
+    pub(crate) fn labels(&self, sanitize: fn(&str) -> Cow<'_, str>) -> Labels {
+        let rows = unique_labels(&self.row_names, self.num_row(), 'r', sanitize);
+        let mut objective = String::from("obj");
+        while rows.contains(&objective) {
+            objective.push('_');
+        }
+        Labels {
+            cols: unique_labels(&self.col_names, self.num_col(), 'c', sanitize),
+            rows,
+            objective,
+        }
+    }
+
+    /// The nonzeros of each column of the constraint matrix, as `(row, value)` pairs.
+    pub(crate) fn column_entries(&self) -> Vec<Vec<(usize, f64)>> {
+        if self.a_format == MATRIX_FORMAT_ROW_WISE {
+            transpose(&vectors(&self.a_matrix), self.num_col())
+        } else {
+            let mut columns = vectors(&self.a_matrix);
+            // A model without rows may leave the column starts out.
+            columns.resize(self.num_col(), Vec::new());
+            columns
+        }
+    }
+
+    /// The nonzeros of each row of the constraint matrix, as `(column, value)` pairs.
+    pub(crate) fn row_entries(&self) -> Vec<Vec<(usize, f64)>> {
+        if self.a_format == MATRIX_FORMAT_ROW_WISE {
+            let mut rows = vectors(&self.a_matrix);
+            rows.resize(self.num_row(), Vec::new());
+            rows
+        } else {
+            transpose(&vectors(&self.a_matrix), self.num_row())
+        }
+    }
+}
+
+/// The names of the columns, rows and objective of a [`Model`] in model files. Unnamed columns
+/// and rows are named `c{j}` and `r{i}` after their index, and the objective `obj`. These and
+/// sanitized names are followed by underscores if another column or row already has the name.
+pub(crate) struct Labels {
+    pub(crate) cols: Vec<String>,
+    pub(crate) rows: Vec<String>,
+    pub(crate) objective: String,
+}
+
+/// The `count` names of `names` after `sanitize`, or `{prefix}{index}` for unnamed ones,
+/// followed by underscores if another name is the same.
+fn unique_labels(
+    names: &[String],
+    count: usize,
+    prefix: char,
+    sanitize: fn(&str) -> Cow<'_, str>,
+) -> Vec<String> {
+    let sanitized: Vec<Cow<'_, str>> = names.iter().map(|name| sanitize(name)).collect();
+    let mut used: HashSet<String> = sanitized
+        .iter()
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_string())
+        .collect();
+    let mut kept = HashSet::new();
+    (0..count)
+        .map(|index| {
+            let mut label = match sanitized.get(index) {
+                Some(name) if !name.is_empty() => {
+                    // The first of the names that are the same once sanitized keeps its name.
+                    if kept.insert(name.as_ref()) {
+                        return name.to_string();
+                    }
+                    name.to_string()
+                }
+                _ => format!("{}{}", prefix, index),
+            };
+            while used.contains(&label) {
+                label.push('_');
+            }
+            used.insert(label.clone());
+            label
+        })
+        .collect()
+}
+
+/// Checks that `matrix` has `num_vec` vectors of indices below `num_index`. Without nonzeros,
+/// the starts may be left out.
+fn check_matrix(
+    what: &str,
+    matrix: &SparseMatrix,
+    num_vec: usize,
+    num_index: usize,
+) -> Result<(), HighsError> {
+    let invalid = |reason: String| Err(HighsError::InvalidModel(format!("{} {}", what, reason)));
+    let num_nz = matrix.num_nz();
+    if matrix.index.len() != num_nz {
+        return invalid(format!(
+            "has {} indices for {} values",
+            matrix.index.len(),
+            num_nz
+        ));
+    }
+    if matrix.start.len() != num_vec && !(matrix.start.is_empty() && num_nz == 0) {
+        return invalid(format!(
+            "has {} starts for {} vectors",
+            matrix.start.len(),
+            num_vec
+        ));
+    }
+    let mut previous = 0;
+    for &start in &matrix.start {
+        if start < previous || rust_len(start) > num_nz {
+            return invalid(format!("has an invalid start {}", start));
+        }
+        previous = start;
+    }
+    if matches!(matrix.start.first(), Some(&start) if start != 0) {
+        return invalid("doesn't start at 0".to_string());
+    }
+    if let Some(&index) = matrix
+        .index
+        .iter()
+        .find(|&&index| index < 0 || rust_len(index) >= num_index)
+    {
+        return invalid(format!("has an invalid index {}", index));
+    }
+    Ok(())
+}
+
+/// The nonzeros of each vector of `matrix`, as `(index, value)` pairs.
+pub(crate) fn vectors(matrix: &SparseMatrix) -> Vec<Vec<(usize, f64)>> {
+    (0..matrix.start.len())
+        .map(|i| {
+            let start = rust_len(matrix.start[i]);
+            let end = matrix
+                .start
+                .get(i + 1)
+                .map_or(matrix.num_nz(), |&end| rust_len(end));
+            (start..end)
+                .map(|k| (rust_len(matrix.index[k]), matrix.value[k]))
+                .collect()
+        })
+        .collect()
+}
+
+/// Turns the vectors of a matrix into the `num_other` vectors of the other orientation.
+fn transpose(vectors: &[Vec<(usize, f64)>], num_other: usize) -> Vec<Vec<(usize, f64)>> {
+    let mut transposed = vec![Vec::new(); num_other];
+    for (i, vector) in vectors.iter().enumerate() {
+        for &(j, value) in vector {
+            transposed[j].push((i, value));
+        }
+    }
+    transposed
+}
+
+impl Highs {
+    /// Returns a copy of the model, with the constraint matrix in `a_format`, which is
+    /// `MATRIX_FORMAT_COLUMN_WISE` or `MATRIX_FORMAT_ROW_WISE`.
+    ///
+    /// This calls `Highs_getModel` twice: first for the dimensions, then to fill buffers of
+    /// these sizes.
+    pub fn get_model(&self, a_format: HighsInt) -> Result<Model, HighsError> {
+        if a_format != MATRIX_FORMAT_COLUMN_WISE && a_format != MATRIX_FORMAT_ROW_WISE {
+            return Err(HighsError::InvalidMatrixFormat(a_format));
+        }
+        let q_format = kHighsHessianFormatTriangular;
+        let (mut num_col, mut num_row, mut num_nz, mut q_num_nz) = (0, 0, 0, 0);
+        let mut sense = OBJECTIVE_SENSE_MINIMIZE;
+        let mut offset = 0.;
+        let status = unsafe {
+            Highs_getModel(
+                self.as_ptr(),
+                a_format,
+                q_format,
+                &mut num_col,
+                &mut num_row,
+                &mut num_nz,
+                &mut q_num_nz,
+                &mut sense,
+                &mut offset,
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                null_mut(),
+            )
+        };
+        HighsError::check("Highs_getModel", status)?;
+
+        let (n_col, n_row) = (rust_len(num_col), rust_len(num_row));
+        let n_vec = if a_format == MATRIX_FORMAT_COLUMN_WISE {
+            n_col
+        } else {
+            n_row
+        };
+        let mut model = Model {
+            sense,
+            offset,
+            col_cost: vec![0.; n_col],
+            col_lower: vec![0.; n_col],
+            col_upper: vec![0.; n_col],
+            row_lower: vec![0.; n_row],
+            row_upper: vec![0.; n_row],
+            a_format,
+            a_matrix: SparseMatrix {
+                start: vec![0; n_vec],
+                index: vec![0; rust_len(num_nz)],
+                value: vec![0.; rust_len(num_nz)],
+            },
+            hessian: if q_num_nz > 0 {
+                SparseMatrix {
+                    start: vec![0; n_col],
+                    index: vec![0; rust_len(q_num_nz)],
+                    value: vec![0.; rust_len(q_num_nz)],
+                }
+            } else {
+                SparseMatrix::default()
+            },
+            integrality: vec![VAR_TYPE_CONTINUOUS; n_col],
+            col_names: self.get_col_names()?,
+            row_names: self.get_row_names()?,
+        };
+        let status = unsafe {
+            Highs_getModel(
+                self.as_ptr(),
+                a_format,
+                q_format,
+                &mut num_col,
+                &mut num_row,
+                &mut num_nz,
+                &mut q_num_nz,
+                &mut sense,
+                &mut offset,
+                model.col_cost.as_mut_ptr(),
+                model.col_lower.as_mut_ptr(),
+                model.col_upper.as_mut_ptr(),
+                model.row_lower.as_mut_ptr(),
+                model.row_upper.as_mut_ptr(),
+                model.a_matrix.start.as_mut_ptr(),
+                model.a_matrix.index.as_mut_ptr(),
+                model.a_matrix.value.as_mut_ptr(),
+                or_null_mut(&mut model.hessian.start),
+                or_null_mut(&mut model.hessian.index),
+                or_null_mut(&mut model.hessian.value),
+                model.integrality.as_mut_ptr(),
+            )
+        };
+        HighsError::check("Highs_getModel", status)?;
+        if model.integrality.iter().all(|&t| t == VAR_TYPE_CONTINUOUS) {
+            model.integrality.clear();
+        }
+        Ok(model)
+    }
+
+    /// Replaces the model of this instance with `model`, using `Highs_passModel`, then names its
+    /// columns and rows. Fails without calling HiGHS if [`Model::validate`] fails.
+    pub fn pass_model(&mut self, model: &Model) -> Result<(), HighsError> {
+        model.validate()?;
+        let status = unsafe {
+            Highs_passModel(
+                self.as_mut_ptr(),
+                c_len(model.num_col()),
+                c_len(model.num_row()),
+                c_len(model.a_matrix.num_nz()),
+                c_len(model.hessian.num_nz()),
+                model.a_format,
+                kHighsHessianFormatTriangular,
+                model.sense,
+                model.offset,
+                model.col_cost.as_ptr(),
+                model.col_lower.as_ptr(),
+                model.col_upper.as_ptr(),
+                model.row_lower.as_ptr(),
+                model.row_upper.as_ptr(),
+                model.a_matrix.start.as_ptr(),
+                model.a_matrix.index.as_ptr(),
+                model.a_matrix.value.as_ptr(),
+                or_null(&model.hessian.start),
+                or_null(&model.hessian.index),
+                or_null(&model.hessian.value),
+                or_null(&model.integrality),
+            )
+        };
+        HighsError::check("Highs_passModel", status)?;
+        self.set_col_names(&model.col_names)?;
+        self.set_row_names(&model.row_names)
+    }
+}
+
+/// The pointer to `slice`, or null if it is empty, for optional C API arrays.
+fn or_null<T>(slice: &[T]) -> *const T {
+    if slice.is_empty() {
+        null()
+    } else {
+        slice.as_ptr()
+    }
+}
+
+/// The pointer to `slice`, or null if it is empty, for optional C API arrays.
+fn or_null_mut<T>(slice: &mut [T]) -> *mut T {
+    if slice.is_empty() {
+        null_mut()
+    } else {
+        slice.as_mut_ptr()
+    }
+}
@@ -0,0 +1,232 @@
+//! Reading and writing model files with `Highs_readModel` and `Highs_writeModel`.
+
+use std::ffi::CString;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::build_info::ZLIB;
+use crate::file_path::{c_path, c_path_to_read};
+use crate::{Highs, HighsInt, Highs_readModel, Highs_writeModel, PathError, STATUS_ERROR};
+
+/// A model file format that HiGHS reads and writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModelFormat {
+    /// MPS, in fixed or free format. HiGHS writes free MPS.
+    Mps,
+    /// The CPLEX LP format.
+    Lp,
+}
+
+impl ModelFormat {
+    /// The file extension of the format, without a leading dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ModelFormat::Mps => "mps",
+            ModelFormat::Lp => "lp",
+        }
+    }
+
+    /// Detects the format of `path` from its extension, ignoring a final `.gz`, so that
+    /// both `model.mps` and `model.mps.gz` are [`ModelFormat::Mps`].
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let path = if is_gzipped(path) {
+            Path::new(path.file_stem()?)
+        } else {
+            path
+        };
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "mps" => Some(ModelFormat::Mps),
+            "lp" => Some(ModelFormat::Lp),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ModelFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ModelFormat::Mps => "MPS",
+            ModelFormat::Lp => "LP",
+        })
+    }
+}
+
+/// Whether `path` has a `.gz` extension.
+fn is_gzipped(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("gz"))
+}
+
+/// An error reading or writing a model file.
+#[derive(Debug)]
+pub enum ModelFileError {
+    /// The extension of the path is not that of a [`ModelFormat`].
+    UnknownFormat(PathBuf),
+    /// The file is gzipped, but HiGHS was built without zlib, see the `libz` feature, or the
+    /// file is to be written, which HiGHS only does uncompressed.
+    CompressionUnsupported(PathBuf),
+    /// The path can't be passed to HiGHS, or the file or its temporary directory couldn't be
+    /// opened or created.
+    Path(PathError),
+    /// HiGHS could not parse the file as a model of the given format. HiGHS logs the
+    /// reason, unless its `output_flag` option is off.
+    Parse { path: PathBuf, format: ModelFormat },
+    /// HiGHS could not write the model to the file.
+    Write { path: PathBuf },
+}
+
+impl fmt::Display for ModelFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModelFileError::UnknownFormat(path) => write!(
+                f,
+                "{} is not a model file, expected a .mps or .lp extension",
+                path.display()
+            ),
+            ModelFileError::CompressionUnsupported(path) => write!(
+                f,
+                "{} is gzipped, which HiGHS doesn't support here",
+                path.display()
+            ),
+            ModelFileError::Path(error) => write!(f, "{}", error),
+            ModelFileError::Parse { path, format } => {
+                write!(f, "HiGHS couldn't parse {} as {}", path.display(), format)
+            }
+            ModelFileError::Write { path } => {
+                write!(f, "HiGHS couldn't write the model to {}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ModelFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ModelFileError::Path(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<PathError> for ModelFileError {
+    fn from(error: PathError) -> Self {
+        ModelFileError::Path(error)
+    }
+}
+
+/// Checks that HiGHS handles the format of `path`, and converts it for the C API.
+fn model_path(path: &Path, writing: bool) -> Result<(ModelFormat, CString), ModelFileError> {
+    let format =
+        ModelFormat::from_path(path).ok_or_else(|| ModelFileError::UnknownFormat(path.into()))?;
+    if is_gzipped(path) && (writing || !ZLIB) {
+        return Err(ModelFileError::CompressionUnsupported(path.into()));
+    }
+    let c_path = if writing {
+        c_path(path)?
+    } else {
+        c_path_to_read(path)?
+    };
+    Ok((format, c_path))
+}
+
+impl Highs {
+    /// Replaces the model with the one in the file at `path`, whose format is detected from
+    /// its extension: `.mps` or `.lp`, optionally followed by `.gz` if HiGHS was built with zlib.
+    pub fn read_model(&mut self, path: impl AsRef<Path>) -> Result<(), ModelFileError> {
+        let path = path.as_ref();
+        let (format, c_path) = model_path(path, false)?;
+        let status: HighsInt = unsafe { Highs_readModel(self.as_mut_ptr(), c_path.as_ptr()) };
+        if status == STATUS_ERROR {
+            return Err(ModelFileError::Parse {
+                path: path.into(),
+                format,
+            });
+        }
+        Ok(())
+    }
+
+    /// Writes the model to the file at `path`, in the format given by its extension,
+    /// `.mps` or `.lp`.
+    pub fn write_model(&mut self, path: impl AsRef<Path>) -> Result<(), ModelFileError> {
+        let path = path.as_ref();
+        let (_, c_path) = model_path(path, true)?;
+        let status: HighsInt = unsafe { Highs_writeModel(self.as_mut_ptr(), c_path.as_ptr()) };
+        if status == STATUS_ERROR {
+            return Err(ModelFileError::Write { path: path.into() });
+        }
+        Ok(())
+    }
+}
+
+/// A model file in a private temporary directory, which is removed when this is dropped.
+///
+/// HiGHS only reads and writes models through files, so the in-memory functions go through one.
+struct TempModelFile {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+impl TempModelFile {
+    fn new(format: ModelFormat) -> Result<Self, ModelFileError> {
+        // Unique within the process, while the process ID tells processes apart. A directory left
+        // behind by an earlier process with the same ID is skipped, since `create_dir` fails.
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        loop {
+            let dir = std::env::temp_dir().join(format!(
+                "highs-sys-{}-{}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            match create_private_dir(&dir) {
+                Ok(()) => {
+                    let path = dir.join(format!("model.{}", format.extension()));
+                    return Ok(TempModelFile { dir, path });
+                }
+                Err(error) if error.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(source) => return Err(PathError::Io { path: dir, source }.into()),
+            }
+        }
+    }
+}
+
+impl Drop for TempModelFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Creates a directory only the current user can access, as models may be confidential.
+fn create_private_dir(dir: &Path) -> io::Result<()> {
+    let mut builder = fs::DirBuilder::new();
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(dir)
+}
+
+impl Highs {
+    /// Replaces the model with the one in `content`, in the given format.
+    ///
+    /// HiGHS only reads files, so `content` is written to a file in a private temporary
+    /// directory, which is removed afterwards.
+    pub fn read_model_from_str(
+        &mut self,
+        format: ModelFormat,
+        content: &str,
+    ) -> Result<(), ModelFileError> {
+        let file = TempModelFile::new(format)?;
+        fs::write(&file.path, content).map_err(PathError::io(&file.path))?;
+        self.read_model(&file.path)
+    }
+
+    /// Returns the model in the given format, written by HiGHS to a file in a private
+    /// temporary directory, which is removed afterwards.
+    pub fn write_model_to_string(&mut self, format: ModelFormat) -> Result<String, ModelFileError> {
+        let file = TempModelFile::new(format)?;
+        self.write_model(&file.path)?;
+        let content = fs::read_to_string(&file.path).map_err(PathError::io(&file.path))?;
+        Ok(content)
+    }
+}
@@ -0,0 +1,376 @@
+//! Building a [`Model`] with variables and linear expressions instead of compressed arrays.
+
+use std::iter::Sum;
+use std::ops::{
+    Add, AddAssign, Mul, Neg, RangeFrom, RangeFull, RangeInclusive, RangeToInclusive, Sub,
+    SubAssign,
+};
+
+use crate::highs::c_len;
+use crate::{
+    HighsInt, Model, SparseMatrix, MATRIX_FORMAT_ROW_WISE, VAR_TYPE_CONTINUOUS, VAR_TYPE_INTEGER,
+};
+
+/// A variable of a [`Model`], i.e. a column, returned by [`Model::add_var`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Var(usize);
+
+impl Var {
+    /// The index of the column, for instance in `Solution::col_value`.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A constraint of a [`Model`], i.e. a row, returned by [`Model::add_constr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Constr(usize);
+
+impl Constr {
+    /// The index of the row, for instance in `Solution::row_dual`.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A linear expression: a sum of variables with coefficients, plus a constant.
+///
+/// Expressions are built from [`Var`]s and `f64`s with `+`, `-` and `*`, and summed over
+/// iterators, as in `2.0 * x + y - 1.0`, `vars.iter().sum()` or
+/// `vars.iter().map(|&v| 3.0 * v).sum()`. A variable may appear in several terms, whose
+/// coefficients are added up in a constraint.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LinExpr {
+    terms: Vec<(Var, f64)>,
+    constant: f64,
+}
+
+impl LinExpr {
+    /// The terms, in the order they were added.
+    pub fn terms(&self) -> &[(Var, f64)] {
+        &self.terms
+    }
+
+    /// The constant.
+    pub fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    /// The value of the expression for the values of the columns, such as `Solution::col_value`.
+    pub fn value(&self, col_value: &[f64]) -> f64 {
+        let terms: f64 = self
+            .terms
+            .iter()
+            .map(|&(var, coefficient)| coefficient * col_value[var.0])
+            .sum();
+        terms + self.constant
+    }
+}
+
+impl From<Var> for LinExpr {
+    fn from(var: Var) -> Self {
+        LinExpr {
+            terms: vec![(var, 1.)],
+            constant: 0.,
+        }
+    }
+}
+
+impl From<&Var> for LinExpr {
+    fn from(&var: &Var) -> Self {
+        LinExpr::from(var)
+    }
+}
+
+impl From<f64> for LinExpr {
+    fn from(constant: f64) -> Self {
+        LinExpr {
+            terms: Vec::new(),
+            constant,
+        }
+    }
+}
+
+impl<T: Into<LinExpr>> AddAssign<T> for LinExpr {
+    fn add_assign(&mut self, other: T) {
+        let other = other.into();
+        self.terms.extend(other.terms);
+        self.constant += other.constant;
+    }
+}
+
+impl<T: Into<LinExpr>> SubAssign<T> for LinExpr {
+    fn sub_assign(&mut self, other: T) {
+        *self += -other.into();
+    }
+}
+
+impl Neg for LinExpr {
+    type Output = LinExpr;
+
+    fn neg(self) -> LinExpr {
+        self * -1.
+    }
+}
+
+impl Neg for Var {
+    type Output = LinExpr;
+
+    fn neg(self) -> LinExpr {
+        -LinExpr::from(self)
+    }
+}
+
+impl Mul<f64> for LinExpr {
+    type Output = LinExpr;
+
+    fn mul(mut self, factor: f64) -> LinExpr {
+        for (_, coefficient) in &mut self.terms {
+            *coefficient *= factor;
+        }
+        self.constant *= factor;
+        self
+    }
+}
+
+impl Mul<f64> for Var {
+    type Output = LinExpr;
+
+    fn mul(self, factor: f64) -> LinExpr {
+        LinExpr::from(self) * factor
+    }
+}
+
+impl Mul<LinExpr> for f64 {
+    type Output = LinExpr;
+
+    fn mul(self, expr: LinExpr) -> LinExpr {
+        expr * self
+    }
+}
+
+impl Mul<Var> for f64 {
+    type Output = LinExpr;
+
+    fn mul(self, var: Var) -> LinExpr {
+        var * self
+    }
+}
+
+impl<T: Into<LinExpr>> Add<T> for LinExpr {
+    type Output = LinExpr;
+
+    fn add(mut self, other: T) -> LinExpr {
+        self += other;
+        self
+    }
+}
+
+impl<T: Into<LinExpr>> Add<T> for Var {
+    type Output = LinExpr;
+
+    fn add(self, other: T) -> LinExpr {
+        LinExpr::from(self) + other
+    }
+}
+
+impl Add<LinExpr> for f64 {
+    type Output = LinExpr;
+
+    fn add(self, expr: LinExpr) -> LinExpr {
+        expr + self
+    }
+}
+
+impl Add<Var> for f64 {
+    type Output = LinExpr;
+
+    fn add(self, var: Var) -> LinExpr {
+        var + self
+    }
+}
+
+impl<T: Into<LinExpr>> Sub<T> for LinExpr {
+    type Output = LinExpr;
+
+    fn sub(mut self, other: T) -> LinExpr {
+        self -= other;
+        self
+    }
+}
+
+impl<T: Into<LinExpr>> Sub<T> for Var {
+    type Output = LinExpr;
+
+    fn sub(self, other: T) -> LinExpr {
+        LinExpr::from(self) - other
+    }
+}
+
+impl Sub<LinExpr> for f64 {
+    type Output = LinExpr;
+
+    fn sub(self, expr: LinExpr) -> LinExpr {
+        -expr + self
+    }
+}
+
+impl Sub<Var> for f64 {
+    type Output = LinExpr;
+
+    fn sub(self, var: Var) -> LinExpr {
+        -var + self
+    }
+}
+
+impl<T: Into<LinExpr>> Sum<T> for LinExpr {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> LinExpr {
+        iter.fold(LinExpr::default(), |sum, term| sum + term)
+    }
+}
+
+/// The bounds of a variable or constraint: an inclusive range such as `0.0..=3.0`, `1.0..`,
+/// `..=6.0` or `..`. Ranges that exclude their end, such as `0.0..1.0`, don't implement it, as
+/// HiGHS bounds are inclusive.
+///
+/// ```compile_fail
+/// highs_sys::Model::default().add_var(0.0..1.0, 0.0);
+/// ```
+pub trait Bounds: sealed::Sealed {}
+
+mod sealed {
+    use super::*;
+
+    /// Keeps [`Bounds`] from being implemented outside this crate.
+    pub trait Sealed {
+        /// The lower and upper bounds, which are infinite for an unbounded side.
+        fn bounds(&self) -> (f64, f64);
+    }
+
+    impl Sealed for RangeInclusive<f64> {
+        fn bounds(&self) -> (f64, f64) {
+            (*self.start(), *self.end())
+        }
+    }
+
+    impl Sealed for RangeFrom<f64> {
+        fn bounds(&self) -> (f64, f64) {
+            (self.start, f64::INFINITY)
+        }
+    }
+
+    impl Sealed for RangeToInclusive<f64> {
+        fn bounds(&self) -> (f64, f64) {
+            (f64::NEG_INFINITY, self.end)
+        }
+    }
+
+    impl Sealed for RangeFull {
+        fn bounds(&self) -> (f64, f64) {
+            (f64::NEG_INFINITY, f64::INFINITY)
+        }
+    }
+}
+
+impl Bounds for RangeInclusive<f64> {}
+impl Bounds for RangeFrom<f64> {}
+impl Bounds for RangeToInclusive<f64> {}
+impl Bounds for RangeFull {}
+
+impl Model {
+    /// Adds a variable with the given bounds and objective cost, and returns it.
+    ///
+    /// The [`Bounds`] are an inclusive range, such as `0.0..=3.0`, `1.0..` or `..` for a free
+    /// variable.
+    pub fn add_var(&mut self, bounds: impl Bounds, cost: f64) -> Var {
+        self.add_column(bounds, cost, VAR_TYPE_CONTINUOUS)
+    }
+
+    /// Adds an integer variable, like [`Model::add_var`].
+    pub fn add_integer_var(&mut self, bounds: impl Bounds, cost: f64) -> Var {
+        self.add_column(bounds, cost, VAR_TYPE_INTEGER)
+    }
+
+    fn add_column(&mut self, bounds: impl Bounds, cost: f64, var_type: HighsInt) -> Var {
+        self.make_row_wise();
+        let (lower, upper) = bounds.bounds();
+        let var = Var(self.num_col());
+        self.col_cost.push(cost);
+        self.col_lower.push(lower);
+        self.col_upper.push(upper);
+        if var_type != VAR_TYPE_CONTINUOUS || !self.integrality.is_empty() {
+            self.integrality.resize(var.0, VAR_TYPE_CONTINUOUS);
+            self.integrality.push(var_type);
+        }
+        if !self.hessian.start.is_empty() {
+            // The new column has no quadratic terms.
+            self.hessian.start.push(c_len(self.hessian.num_nz()));
+        }
+        if !self.col_names.is_empty() {
+            self.col_names.push(String::new());
+        }
+        var
+    }
+
+    /// Adds the constraint that `expr` lies within `bounds`, such as `..=14.0`, `8.0..` or
+    /// `10.0..=10.0`, and returns it.
+    ///
+    /// The constant of `expr` is moved to the bounds, and the coefficients of a variable that
+    /// appears several times are added up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `expr` has a variable of another model, with a larger index than any variable
+    /// of this one.
+    pub fn add_constr(&mut self, expr: impl Into<LinExpr>, bounds: impl Bounds) -> Constr {
+        self.make_row_wise();
+        let expr = expr.into();
+        let (lower, upper) = bounds.bounds();
+        let mut terms = expr.terms;
+        terms.sort_by_key(|&(var, _)| var);
+        let mut merged: Vec<(Var, f64)> = Vec::with_capacity(terms.len());
+        for (var, coefficient) in terms {
+            assert!(
+                var.0 < self.num_col(),
+                "variable {} is not in this model",
+                var.0
+            );
+            match merged.last_mut() {
+                Some((last, sum)) if *last == var => *sum += coefficient,
+                _ => merged.push((var, coefficient)),
+            }
+        }
+
+        let constr = Constr(self.num_row());
+        self.a_matrix.start.push(c_len(self.a_matrix.num_nz()));
+        for (var, coefficient) in merged {
+            if coefficient != 0. {
+                self.a_matrix.index.push(c_len(var.0));
+                self.a_matrix.value.push(coefficient);
+            }
+        }
+        self.row_lower.push(lower - expr.constant);
+        self.row_upper.push(upper - expr.constant);
+        if !self.row_names.is_empty() {
+            self.row_names.push(String::new());
+        }
+        constr
+    }
+
+    /// Stores the constraint matrix row-wise, so that rows and columns can be added to it.
+    fn make_row_wise(&mut self) {
+        if self.a_format == MATRIX_FORMAT_ROW_WISE {
+            return;
+        }
+        let mut a_matrix = SparseMatrix::default();
+        for entries in self.row_entries() {
+            a_matrix.start.push(c_len(a_matrix.index.len()));
+            for (j, value) in entries {
+                a_matrix.index.push(c_len(j));
+                a_matrix.value.push(value);
+            }
+        }
+        self.a_format = MATRIX_FORMAT_ROW_WISE;
+        self.a_matrix = a_matrix;
+    }
+}
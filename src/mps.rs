@@ -0,0 +1,745 @@
+//! A native reader and writer of MPS files for [`Model`], to inspect and transform models
+//! without a solver instance.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Write as _};
+
+use crate::highs::c_len;
+use crate::model::vectors;
+use crate::{
+    Hessian, HessianError, HighsInt, Model, SparseMatrix, MATRIX_FORMAT_COLUMN_WISE,
+    OBJECTIVE_SENSE_MAXIMIZE, OBJECTIVE_SENSE_MINIMIZE, VAR_TYPE_CONTINUOUS, VAR_TYPE_INTEGER,
+    VAR_TYPE_SEMI_CONTINUOUS, VAR_TYPE_SEMI_INTEGER,
+};
+
+/// Bounds and right-hand sides at least this large are infinite, as in HiGHS' MPS reader.
+const INFINITY_THRESHOLD: f64 = 1e30;
+
+/// The layout of the lines of an MPS file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MpsFormat {
+    /// Fields separated by whitespace. Names cannot contain spaces, and the set names of the
+    /// RHS, RANGES and BOUNDS sections may be left out.
+    Free,
+    /// Fields at fixed columns, so that names may contain spaces.
+    Fixed,
+}
+
+/// What is wrong in an MPS file, see [`MpsError`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MpsErrorKind {
+    /// A section header that isn't one of the MPS sections.
+    UnknownSection(String),
+    /// A data line before the first section header.
+    DataOutsideSection,
+    /// A line with too few or too many fields for its section.
+    WrongFieldCount,
+    /// A row type other than `N`, `L`, `G` or `E`.
+    InvalidRowType(String),
+    /// A bound type other than `UP`, `LO`, `FX`, `FR`, `MI`, `PL`, `BV`, `LI`, `UI` or `SC`.
+    InvalidBoundType(String),
+    /// An objective sense other than `MIN`, `MAX`, `MINIMIZE` or `MAXIMIZE`.
+    InvalidObjectiveSense(String),
+    /// A field that should be a number but isn't.
+    InvalidNumber(String),
+    /// A row name that is not declared in the ROWS section.
+    UnknownRow(String),
+    /// A column name that does not appear in the COLUMNS section.
+    UnknownColumn(String),
+    /// A row declared twice.
+    DuplicateRow(String),
+    /// A matrix coefficient given twice.
+    DuplicateEntry { row: String, col: String },
+    /// The ROWS section declares no `N` row for the objective.
+    NoObjectiveRow,
+    /// The QUADOBJ or QMATRIX section is invalid.
+    Hessian(HessianError),
+    /// The file ends without an ENDATA line.
+    MissingEndata,
+}
+
+/// An error in an MPS file, at a 1-based `line`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MpsError {
+    pub line: usize,
+    pub kind: MpsErrorKind,
+}
+
+impl fmt::Display for MpsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            MpsErrorKind::UnknownSection(name) => write!(f, "unknown section {}", name),
+            MpsErrorKind::DataOutsideSection => write!(f, "data before the first section"),
+            MpsErrorKind::WrongFieldCount => write!(f, "wrong number of fields"),
+            MpsErrorKind::InvalidRowType(name) => write!(f, "invalid row type {}", name),
+            MpsErrorKind::InvalidBoundType(name) => write!(f, "invalid bound type {}", name),
+            MpsErrorKind::InvalidObjectiveSense(name) => {
+                write!(f, "invalid objective sense {}", name)
+            }
+            MpsErrorKind::InvalidNumber(field) => write!(f, "{:?} is not a number", field),
+            MpsErrorKind::UnknownRow(name) => write!(f, "unknown row {}", name),
+            MpsErrorKind::UnknownColumn(name) => write!(f, "unknown column {}", name),
+            MpsErrorKind::DuplicateRow(name) => write!(f, "row {} is declared twice", name),
+            MpsErrorKind::DuplicateEntry { row, col } => {
+                write!(
+                    f,
+                    "the coefficient of column {} in row {} is given twice",
+                    col, row
+                )
+            }
+            MpsErrorKind::NoObjectiveRow => write!(f, "no objective row"),
+            MpsErrorKind::Hessian(error) => write!(f, "{}", error),
+            MpsErrorKind::MissingEndata => write!(f, "missing ENDATA"),
+        }
+    }
+}
+
+impl std::error::Error for MpsError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    ObjSense,
+    Rows,
+    Columns,
+    Rhs,
+    Ranges,
+    Bounds,
+    QuadObj,
+    QMatrix,
+}
+
+/// The sections of a data line, padded with empty fields, in the fixed MPS layout:
+/// a type, a name, a name, a number, a name and a number.
+type Fields<'a> = [&'a str; 6];
+
+/// Splits a data line into the fields of the fixed layout.
+fn fields(line: &str, format: MpsFormat, section: Section) -> Option<Fields<'_>> {
+    let mut fields = [""; 6];
+    match format {
+        MpsFormat::Fixed => {
+            // Columns 2-3, 5-12, 15-22, 25-36, 40-47 and 50-61, with longer numbers tolerated.
+            let ranges = [
+                (1, 3),
+                (4, 12),
+                (14, 22),
+                (24, 39),
+                (39, 47),
+                (49, line.len()),
+            ];
+            for (field, &(start, end)) in fields.iter_mut().zip(&ranges) {
+                let end = end.min(line.len());
+                *field = line.get(start.min(end)..end).unwrap_or("").trim();
+            }
+        }
+        MpsFormat::Free => {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let first = match section {
+                Section::ObjSense | Section::Rows | Section::Bounds => 0,
+                Section::Columns | Section::QuadObj | Section::QMatrix => 1,
+                // Without the optional set name, the row names and values come in pairs.
+                Section::Rhs | Section::Ranges if tokens.len() % 2 == 0 => 2,
+                Section::Rhs | Section::Ranges => 1,
+            };
+            let tokens = match section {
+                Section::Bounds => free_bound_fields(&tokens)?,
+                _ => tokens,
+            };
+            if first + tokens.len() > fields.len() {
+                return None;
+            }
+            fields[first..first + tokens.len()].copy_from_slice(&tokens);
+        }
+    }
+    Some(fields)
+}
+
+/// Inserts an empty set name into the tokens of a free BOUNDS line that leaves it out.
+fn free_bound_fields<'a>(tokens: &[&'a str]) -> Option<Vec<&'a str>> {
+    let (&bound_type, rest) = tokens.split_first()?;
+    let has_set = match bound_type.to_ascii_uppercase().as_str() {
+        "FR" | "MI" | "PL" => rest.len() == 2,
+        // The value is optional for these.
+        "BV" | "SC" => rest.len() == 3 || (rest.len() == 2 && rest[1].parse::<f64>().is_err()),
+        _ => rest.len() == 3,
+    };
+    let mut fields = vec![bound_type];
+    if !has_set {
+        fields.push("");
+    }
+    fields.extend_from_slice(rest);
+    Some(fields)
+}
+
+/// Replaces the whitespace in `name` with underscores, since free MPS separates fields with it.
+fn mps_name(name: &str) -> Cow<'_, str> {
+    if name.contains(char::is_whitespace) {
+        Cow::Owned(name.replace(char::is_whitespace, "_"))
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+/// Formats a number so that it reads back exactly, with infinities as 1e30.
+fn format_number(value: f64) -> String {
+    if value == f64::INFINITY {
+        "1e30".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "-1e30".to_string()
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+fn number(field: &str) -> Result<f64, MpsErrorKind> {
+    field
+        .parse()
+        .map_err(|_| MpsErrorKind::InvalidNumber(field.to_string()))
+}
+
+/// A number that HiGHS would treat as infinite if it is large enough.
+fn bound(field: &str) -> Result<f64, MpsErrorKind> {
+    let value = number(field)?;
+    Ok(if value >= INFINITY_THRESHOLD {
+        f64::INFINITY
+    } else if value <= -INFINITY_THRESHOLD {
+        f64::NEG_INFINITY
+    } else {
+        value
+    })
+}
+
+#[derive(Default)]
+struct Parser {
+    sense: Option<HighsInt>,
+    objective: Option<String>,
+    /// `N` rows other than the objective, which are dropped, like HiGHS does.
+    free_rows: HashSet<String>,
+    rows: HashMap<String, usize>,
+    row_types: Vec<char>,
+    rhs: Vec<f64>,
+    range: Vec<Option<f64>>,
+    offset: f64,
+    row_names: Vec<String>,
+    columns: HashMap<String, usize>,
+    col_names: Vec<String>,
+    col_cost: Vec<f64>,
+    col_entries: Vec<Vec<(usize, f64)>>,
+    entries: HashSet<(usize, usize)>,
+    col_lower: Vec<f64>,
+    col_upper: Vec<f64>,
+    integrality: Vec<HighsInt>,
+    in_integer_marker: bool,
+    hessian: Vec<(usize, usize, f64)>,
+    full_hessian: bool,
+}
+
+/// Where a row name points to.
+enum Row {
+    Objective,
+    Free,
+    Constraint(usize),
+}
+
+impl Parser {
+    fn row(&self, name: &str) -> Result<Row, MpsErrorKind> {
+        if self.objective.as_deref() == Some(name) {
+            Ok(Row::Objective)
+        } else if self.free_rows.contains(name) {
+            Ok(Row::Free)
+        } else {
+            self.rows
+                .get(name)
+                .map(|&row| Row::Constraint(row))
+                .ok_or_else(|| MpsErrorKind::UnknownRow(name.to_string()))
+        }
+    }
+
+    fn column(&self, name: &str) -> Result<usize, MpsErrorKind> {
+        self.columns
+            .get(name)
+            .copied()
+            .ok_or_else(|| MpsErrorKind::UnknownColumn(name.to_string()))
+    }
+
+    fn objective_sense(&mut self, name: &str) -> Result<(), MpsErrorKind> {
+        self.sense = Some(match name.to_ascii_uppercase().as_str() {
+            "MIN" | "MINIMIZE" => OBJECTIVE_SENSE_MINIMIZE,
+            "MAX" | "MAXIMIZE" => OBJECTIVE_SENSE_MAXIMIZE,
+            _ => return Err(MpsErrorKind::InvalidObjectiveSense(name.to_string())),
+        });
+        Ok(())
+    }
+
+    fn add_row(&mut self, row_type: &str, name: &str) -> Result<(), MpsErrorKind> {
+        if self.rows.contains_key(name)
+            || self.free_rows.contains(name)
+            || self.objective.as_deref() == Some(name)
+        {
+            return Err(MpsErrorKind::DuplicateRow(name.to_string()));
+        }
+        match row_type.to_ascii_uppercase().as_str() {
+            "N" if self.objective.is_none() => self.objective = Some(name.to_string()),
+            "N" => {
+                self.free_rows.insert(name.to_string());
+            }
+            row_type @ ("L" | "G" | "E") => {
+                self.rows.insert(name.to_string(), self.row_types.len());
+                self.row_names.push(name.to_string());
+                self.row_types.push(row_type.chars().next().unwrap());
+                self.rhs.push(0.);
+                self.range.push(None);
+            }
+            _ => return Err(MpsErrorKind::InvalidRowType(row_type.to_string())),
+        }
+        Ok(())
+    }
+
+    fn add_coefficient(&mut self, col: &str, row: &str, value: &str) -> Result<(), MpsErrorKind> {
+        let value = number(value)?;
+        let j = match self.columns.get(col) {
+            Some(&j) => j,
+            None => {
+                let j = self.col_cost.len();
+                self.columns.insert(col.to_string(), j);
+                self.col_names.push(col.to_string());
+                self.col_cost.push(0.);
+                self.col_entries.push(Vec::new());
+                self.col_lower.push(0.);
+                self.col_upper.push(f64::INFINITY);
+                self.integrality.push(if self.in_integer_marker {
+                    VAR_TYPE_INTEGER
+                } else {
+                    VAR_TYPE_CONTINUOUS
+                });
+                j
+            }
+        };
+        match self.row(row)? {
+            Row::Objective => self.col_cost[j] = value,
+            Row::Free => (),
+            Row::Constraint(i) => {
+                if !self.entries.insert((i, j)) {
+                    return Err(MpsErrorKind::DuplicateEntry {
+                        row: row.to_string(),
+                        col: col.to_string(),
+                    });
+                }
+                self.col_entries[j].push((i, value));
+            }
+        }
+        Ok(())
+    }
+
+    fn set_rhs(&mut self, row: &str, value: &str) -> Result<(), MpsErrorKind> {
+        let value = bound(value)?;
+        match self.row(row)? {
+            // The right-hand side of the objective is minus its constant term.
+            Row::Objective => self.offset = -value,
+            Row::Free => (),
+            Row::Constraint(i) => self.rhs[i] = value,
+        }
+        Ok(())
+    }
+
+    fn set_range(&mut self, row: &str, value: &str) -> Result<(), MpsErrorKind> {
+        let value = bound(value)?;
+        if let Row::Constraint(i) = self.row(row)? {
+            self.range[i] = Some(value);
+        }
+        Ok(())
+    }
+
+    fn set_bound(&mut self, bound_type: &str, col: &str, value: &str) -> Result<(), MpsErrorKind> {
+        let j = self.column(col)?;
+        let bound_type = bound_type.to_ascii_uppercase();
+        let value = match bound_type.as_str() {
+            "FR" | "MI" | "PL" => 0.,
+            "BV" if value.is_empty() => 1.,
+            "SC" if value.is_empty() => f64::INFINITY,
+            _ => bound(value)?,
+        };
+        match bound_type.as_str() {
+            "UP" => {
+                // A negative upper bound makes a default lower bound of 0 infeasible, so the
+                // lower bound becomes -inf, as in HiGHS and other MPS readers.
+                if value < 0. && self.col_lower[j] == 0. {
+                    self.col_lower[j] = f64::NEG_INFINITY;
+                }
+                self.col_upper[j] = value;
+            }
+            "LO" => self.col_lower[j] = value,
+            "FX" => {
+                self.col_lower[j] = value;
+                self.col_upper[j] = value;
+            }
+            "FR" => {
+                self.col_lower[j] = f64::NEG_INFINITY;
+                self.col_upper[j] = f64::INFINITY;
+            }
+            "MI" => self.col_lower[j] = f64::NEG_INFINITY,
+            "PL" => self.col_upper[j] = f64::INFINITY,
+            "BV" => {
+                self.integrality[j] = VAR_TYPE_INTEGER;
+                self.col_lower[j] = 0.;
+                self.col_upper[j] = 1.;
+            }
+            "LI" => {
+                self.integrality[j] = VAR_TYPE_INTEGER;
+                self.col_lower[j] = value;
+            }
+            "UI" => {
+                self.integrality[j] = VAR_TYPE_INTEGER;
+                self.col_upper[j] = value;
+            }
+            "SC" => {
+                self.integrality[j] = if self.integrality[j] == VAR_TYPE_INTEGER {
+                    VAR_TYPE_SEMI_INTEGER
+                } else {
+                    VAR_TYPE_SEMI_CONTINUOUS
+                };
+                self.col_upper[j] = value;
+            }
+            _ => return Err(MpsErrorKind::InvalidBoundType(bound_type)),
+        }
+        Ok(())
+    }
+
+    fn add_hessian_entry(
+        &mut self,
+        col1: &str,
+        col2: &str,
+        value: &str,
+    ) -> Result<(), MpsErrorKind> {
+        let entry = (self.column(col1)?, self.column(col2)?, number(value)?);
+        self.hessian.push(entry);
+        Ok(())
+    }
+
+    /// Handles a data line of `section`.
+    fn data(&mut self, section: Section, f: Fields<'_>) -> Result<(), MpsErrorKind> {
+        let missing = |field: &str| field.is_empty();
+        match section {
+            Section::ObjSense => unreachable!("the objective sense is not split into fields"),
+            Section::Rows => {
+                if missing(f[0]) || missing(f[1]) || !missing(f[2]) {
+                    return Err(MpsErrorKind::WrongFieldCount);
+                }
+                self.add_row(f[0], f[1])
+            }
+            Section::Columns | Section::Rhs | Section::Ranges => {
+                if missing(f[2]) || missing(f[3]) || missing(f[4]) != missing(f[5]) {
+                    return Err(MpsErrorKind::WrongFieldCount);
+                }
+                for (row, value) in [(f[2], f[3]), (f[4], f[5])] {
+                    if missing(row) {
+                        continue;
+                    }
+                    match section {
+                        Section::Columns => self.add_coefficient(f[1], row, value)?,
+                        Section::Rhs => self.set_rhs(row, value)?,
+                        _ => self.set_range(row, value)?,
+                    }
+                }
+                Ok(())
+            }
+            Section::Bounds => {
+                if missing(f[0]) || missing(f[2]) || !missing(f[4]) {
+                    return Err(MpsErrorKind::WrongFieldCount);
+                }
+                self.set_bound(f[0], f[2], f[3])
+            }
+            Section::QuadObj | Section::QMatrix => {
+                if missing(f[1]) || missing(f[2]) || missing(f[3]) || !missing(f[4]) {
+                    return Err(MpsErrorKind::WrongFieldCount);
+                }
+                self.add_hessian_entry(f[1], f[2], f[3])
+            }
+        }
+    }
+
+    fn into_model(self) -> Result<Model, MpsErrorKind> {
+        if self.objective.is_none() {
+            return Err(MpsErrorKind::NoObjectiveRow);
+        }
+        let num_row = self.row_types.len();
+        let mut row_lower = vec![0.; num_row];
+        let mut row_upper = vec![0.; num_row];
+        for i in 0..num_row {
+            let rhs = self.rhs[i];
+            let (lower, upper) = match (self.row_types[i], self.range[i]) {
+                ('L', None) => (f64::NEG_INFINITY, rhs),
+                ('L', Some(range)) => (rhs - range.abs(), rhs),
+                ('G', None) => (rhs, f64::INFINITY),
+                ('G', Some(range)) => (rhs, rhs + range.abs()),
+                (_, Some(range)) if range < 0. => (rhs + range, rhs),
+                (_, range) => (rhs, rhs + range.unwrap_or(0.)),
+            };
+            row_lower[i] = lower;
+            row_upper[i] = upper;
+        }
+
+        let num_col = self.col_cost.len();
+        let mut a_matrix = SparseMatrix::default();
+        for entries in &self.col_entries {
+            a_matrix.start.push(c_len(a_matrix.index.len()));
+            for &(i, value) in entries {
+                a_matrix.index.push(c_len(i));
+                a_matrix.value.push(value);
+            }
+        }
+        let hessian = if self.hessian.is_empty() {
+            SparseMatrix::default()
+        } else if self.full_hessian {
+            Hessian::from_symmetric_triplets(num_col, &self.hessian)
+                .map_err(MpsErrorKind::Hessian)?
+                .into()
+        } else {
+            Hessian::from_triangular_triplets(num_col, &self.hessian)
+                .map_err(MpsErrorKind::Hessian)?
+                .into()
+        };
+        let mut integrality = self.integrality;
+        if integrality.iter().all(|&t| t == VAR_TYPE_CONTINUOUS) {
+            integrality.clear();
+        }
+        Ok(Model {
+            sense: self.sense.unwrap_or(OBJECTIVE_SENSE_MINIMIZE),
+            offset: self.offset,
+            col_cost: self.col_cost,
+            col_lower: self.col_lower,
+            col_upper: self.col_upper,
+            row_lower,
+            row_upper,
+            a_format: MATRIX_FORMAT_COLUMN_WISE,
+            a_matrix,
+            hessian,
+            integrality,
+            col_names: self.col_names,
+            row_names: self.row_names,
+        })
+    }
+}
+
+impl Model {
+    /// Parses an MPS file, in free format or, failing that, in fixed format, like HiGHS.
+    /// On failure, the error of the free format parser is returned.
+    pub fn from_mps(content: &str) -> Result<Model, MpsError> {
+        Model::from_mps_with_format(content, MpsFormat::Free).or_else(|free_error| {
+            Model::from_mps_with_format(content, MpsFormat::Fixed).map_err(|_| free_error)
+        })
+    }
+
+    /// Parses an MPS file in the given format.
+    ///
+    /// Integer columns are marked with `INTORG`/`INTEND` markers or `BV`, `LI` and `UI`
+    /// bounds. An `SC` bound makes a column semi-continuous, or semi-integer if it is an integer
+    /// column. The Hessian is read from a QUADOBJ section, with one triangle of the matrix, or a
+    /// QMATRIX section, with the full matrix. The names of the columns and of the rows other than
+    /// the objective are kept.
+    pub fn from_mps_with_format(content: &str, format: MpsFormat) -> Result<Model, MpsError> {
+        let mut parser = Parser::default();
+        let mut section = None;
+        for (index, line) in content.lines().enumerate() {
+            let error = |kind| MpsError {
+                line: index + 1,
+                kind,
+            };
+            let line = line.trim_end();
+            if line.trim_start().is_empty() || line.starts_with('*') {
+                continue;
+            }
+            // In COLUMNS, markers delimit the integer columns.
+            if section == Some(Section::Columns) && line.contains("'MARKER'") {
+                if line.contains("'INTORG'") {
+                    parser.in_integer_marker = true;
+                } else if line.contains("'INTEND'") {
+                    parser.in_integer_marker = false;
+                }
+                continue;
+            }
+            if !line.starts_with(char::is_whitespace) {
+                let mut words = line.split_whitespace();
+                let header = words.next().unwrap_or_default().to_ascii_uppercase();
+                section = Some(match header.as_str() {
+                    "NAME" => continue,
+                    "ENDATA" => {
+                        return parser.into_model().map_err(error);
+                    }
+                    "OBJSENSE" => {
+                        // The sense may follow on the same line.
+                        if let Some(sense) = words.next() {
+                            parser.objective_sense(sense).map_err(error)?;
+                        }
+                        Section::ObjSense
+                    }
+                    "ROWS" => Section::Rows,
+                    "COLUMNS" => Section::Columns,
+                    "RHS" => Section::Rhs,
+                    "RANGES" => Section::Ranges,
+                    "BOUNDS" => Section::Bounds,
+                    "QUADOBJ" => Section::QuadObj,
+                    "QMATRIX" => {
+                        parser.full_hessian = true;
+                        Section::QMatrix
+                    }
+                    _ => return Err(error(MpsErrorKind::UnknownSection(header))),
+                });
+                continue;
+            }
+            let section = section.ok_or_else(|| error(MpsErrorKind::DataOutsideSection))?;
+            if section == Section::ObjSense {
+                // A single word, wherever it is on the line.
+                parser.objective_sense(line.trim()).map_err(error)?;
+                continue;
+            }
+            let fields = fields(line, format, section)
+                .ok_or_else(|| error(MpsErrorKind::WrongFieldCount))?;
+            parser.data(section, fields).map_err(error)?;
+        }
+        Err(MpsError {
+            line: content.lines().count(),
+            kind: MpsErrorKind::MissingEndata,
+        })
+    }
+
+    /// Writes the model in free MPS format. Unnamed columns are named `c0`, `c1`... and unnamed
+    /// rows `r0`, `r1`..., after their index, and spaces in names are replaced with underscores,
+    /// followed by more underscores if another column or row already has this name.
+    ///
+    /// Implicit integer columns are written as integer columns.
+    pub fn to_mps(&self) -> String {
+        let mut mps = String::new();
+        self.write_mps(&mut mps)
+            .expect("writing to a String cannot fail");
+        mps
+    }
+
+    fn write_mps(&self, out: &mut String) -> fmt::Result {
+        let integrality = |j: usize| {
+            self.integrality
+                .get(j)
+                .copied()
+                .unwrap_or(VAR_TYPE_CONTINUOUS)
+        };
+        let is_integer = |j: usize| {
+            let t = integrality(j);
+            t != VAR_TYPE_CONTINUOUS && t != VAR_TYPE_SEMI_CONTINUOUS
+        };
+
+        let labels = self.labels(mps_name);
+        let objective = &labels.objective;
+        writeln!(out, "NAME")?;
+        if self.sense == OBJECTIVE_SENSE_MAXIMIZE {
+            writeln!(out, "OBJSENSE\n    MAX")?;
+        }
+        writeln!(out, "ROWS\n N  {}", objective)?;
+        for i in 0..self.num_row() {
+            let (lower, upper) = (self.row_lower[i], self.row_upper[i]);
+            let row_type = if lower == upper {
+                'E'
+            } else if lower > f64::NEG_INFINITY {
+                'G'
+            } else {
+                // Including free rows, whose right-hand side is then infinite.
+                'L'
+            };
+            writeln!(out, " {}  {}", row_type, labels.rows[i])?;
+        }
+
+        writeln!(out, "COLUMNS")?;
+        let mut in_marker = false;
+        for (j, entries) in self.column_entries().iter().enumerate() {
+            if is_integer(j) != in_marker {
+                in_marker = !in_marker;
+                let marker = if in_marker { "'INTORG'" } else { "'INTEND'" };
+                writeln!(out, "    MARKER    'MARKER'  {}", marker)?;
+            }
+            if self.col_cost[j] != 0. || entries.is_empty() {
+                let cost = format_number(self.col_cost[j]);
+                writeln!(out, "    {}  {}  {}", labels.cols[j], objective, cost)?;
+            }
+            for &(i, value) in entries {
+                let (col, row) = (&labels.cols[j], &labels.rows[i]);
+                writeln!(out, "    {}  {}  {}", col, row, format_number(value))?;
+            }
+        }
+        if in_marker {
+            writeln!(out, "    MARKER    'MARKER'  'INTEND'")?;
+        }
+
+        writeln!(out, "RHS")?;
+        if self.offset != 0. {
+            writeln!(
+                out,
+                "    rhs  {}  {}",
+                objective,
+                format_number(-self.offset)
+            )?;
+        }
+        for i in 0..self.num_row() {
+            let (lower, upper) = (self.row_lower[i], self.row_upper[i]);
+            let rhs = if lower > f64::NEG_INFINITY {
+                lower
+            } else {
+                upper
+            };
+            if rhs != 0. {
+                writeln!(out, "    rhs  {}  {}", labels.rows[i], format_number(rhs))?;
+            }
+        }
+
+        let ranged: Vec<usize> = (0..self.num_row())
+            .filter(|&i| {
+                let (lower, upper) = (self.row_lower[i], self.row_upper[i]);
+                lower != upper && lower.is_finite() && upper.is_finite()
+            })
+            .collect();
+        if !ranged.is_empty() {
+            writeln!(out, "RANGES")?;
+            for i in ranged {
+                let range = self.row_upper[i] - self.row_lower[i];
+                writeln!(out, "    rng  {}  {}", labels.rows[i], format_number(range))?;
+            }
+        }
+
+        writeln!(out, "BOUNDS")?;
+        for j in 0..self.num_col() {
+            let col = &labels.cols[j];
+            let (lower, upper) = (self.col_lower[j], self.col_upper[j]);
+            let semi = matches!(
+                integrality(j),
+                VAR_TYPE_SEMI_CONTINUOUS | VAR_TYPE_SEMI_INTEGER
+            );
+            if semi {
+                writeln!(out, " SC bnd  {}  {}", col, format_number(upper))?;
+            } else if lower == upper {
+                writeln!(out, " FX bnd  {}  {}", col, format_number(lower))?;
+                continue;
+            } else if lower == f64::NEG_INFINITY && upper == f64::INFINITY {
+                writeln!(out, " FR bnd  {}", col)?;
+                continue;
+            } else if upper < f64::INFINITY {
+                writeln!(out, " UP bnd  {}  {}", col, format_number(upper))?;
+            }
+            // After UP, which lowers a default lower bound of 0 to -inf if it is negative.
+            if lower == f64::NEG_INFINITY {
+                writeln!(out, " MI bnd  {}", col)?;
+            } else if lower != 0. || upper < 0. {
+                writeln!(out, " LO bnd  {}  {}", col, format_number(lower))?;
+            }
+        }
+
+        if self.hessian.num_nz() > 0 {
+            writeln!(out, "QUADOBJ")?;
+            for (j, entries) in vectors(&self.hessian).iter().enumerate() {
+                for &(i, value) in entries {
+                    let (first, second) = (&labels.cols[j], &labels.cols[i]);
+                    writeln!(out, "    {}  {}  {}", first, second, format_number(value))?;
+                }
+            }
+        }
+        writeln!(out, "ENDATA")
+    }
+}
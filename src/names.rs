@@ -0,0 +1,194 @@
+//! Names of the columns and rows of a HiGHS instance.
+
+use std::collections::HashSet;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+
+use crate::highs::{c_len, c_string, rust_len};
+use crate::{
+    kHighsMaximumStringLength, Highs, HighsError, HighsInt, Highs_getBoolOptionValue,
+    Highs_getColByName, Highs_getColName, Highs_getNumCol, Highs_getNumRow, Highs_getRowByName,
+    Highs_getRowName, Highs_passColName, Highs_passRowName, Highs_setBoolOptionValue,
+};
+
+/// The C API functions for the names of either columns or rows.
+struct Names {
+    count: unsafe extern "C" fn(*const c_void) -> HighsInt,
+    get: (
+        &'static str,
+        unsafe extern "C" fn(*const c_void, HighsInt, *mut c_char) -> HighsInt,
+    ),
+    pass: (
+        &'static str,
+        unsafe extern "C" fn(*const c_void, HighsInt, *const c_char) -> HighsInt,
+    ),
+    find: (
+        &'static str,
+        unsafe extern "C" fn(*const c_void, *const c_char, *mut HighsInt) -> HighsInt,
+    ),
+}
+
+const COLUMNS: Names = Names {
+    count: Highs_getNumCol,
+    get: ("Highs_getColName", Highs_getColName),
+    pass: ("Highs_passColName", Highs_passColName),
+    find: ("Highs_getColByName", Highs_getColByName),
+};
+
+const ROWS: Names = Names {
+    count: Highs_getNumRow,
+    get: ("Highs_getRowName", Highs_getRowName),
+    pass: ("Highs_passRowName", Highs_passRowName),
+    find: ("Highs_getRowByName", Highs_getRowByName),
+};
+
+/// Checks that the non-empty names are unique.
+pub(crate) fn check_unique_names(names: &[impl AsRef<str>]) -> Result<(), HighsError> {
+    let mut seen = HashSet::new();
+    for name in names.iter().map(AsRef::as_ref) {
+        if !name.is_empty() && !seen.insert(name) {
+            return Err(HighsError::DuplicateName(name.to_string()));
+        }
+    }
+    Ok(())
+}
+
+impl Highs {
+    /// Calls `f` with the `output_flag` option off, since HiGHS logs failed name lookups as
+    /// errors, although they are expected here.
+    fn quietly<T>(&self, f: impl FnOnce() -> T) -> T {
+        let option = c_string("output_flag").unwrap();
+        let mut output: HighsInt = 0;
+        unsafe { Highs_getBoolOptionValue(self.as_ptr(), option.as_ptr(), &mut output) };
+        // Changing an option through a shared reference is sound, since `Highs` is not `Sync`,
+        // and restoring it makes the change invisible to the caller.
+        let ptr = self.as_ptr() as *mut c_void;
+        unsafe { Highs_setBoolOptionValue(ptr, option.as_ptr(), 0) };
+        let result = f();
+        unsafe { Highs_setBoolOptionValue(ptr, option.as_ptr(), output) };
+        result
+    }
+
+    fn get_name(&self, names: &Names, index: usize) -> Result<String, HighsError> {
+        let mut name = vec![0 as c_char; kHighsMaximumStringLength as usize];
+        let (function, get) = names.get;
+        let status = unsafe { get(self.as_ptr(), c_len(index), name.as_mut_ptr()) };
+        HighsError::check(function, status)?;
+        let name = unsafe { CStr::from_ptr(name.as_ptr()) };
+        Ok(name.to_string_lossy().into_owned())
+    }
+
+    fn get_names(&self, names: &Names) -> Result<Vec<String>, HighsError> {
+        let count = rust_len(unsafe { (names.count)(self.as_ptr()) });
+        // HiGHS has no names at all for a model that was never named.
+        if count == 0 || self.quietly(|| self.get_name(names, 0)).is_err() {
+            return Ok(Vec::new());
+        }
+        let all = (0..count)
+            .map(|index| self.get_name(names, index))
+            .collect::<Result<Vec<_>, _>>()?;
+        if all.iter().all(String::is_empty) {
+            return Ok(Vec::new());
+        }
+        Ok(all)
+    }
+
+    fn find_name(&self, names: &Names, name: &str) -> Result<Option<usize>, HighsError> {
+        let c_name = c_string(name)?;
+        let mut index = 0;
+        let (function, find) = names.find;
+        let status = self.quietly(|| unsafe { find(self.as_ptr(), c_name.as_ptr(), &mut index) });
+        // HiGHS fails if no name matches.
+        Ok(HighsError::check(function, status)
+            .ok()
+            .map(|()| rust_len(index)))
+    }
+
+    fn set_name(&mut self, names: &Names, index: usize, name: &str) -> Result<(), HighsError> {
+        match self.find_name(names, name)? {
+            Some(other) if other != index => Err(HighsError::DuplicateName(name.to_string())),
+            _ => self.pass_name(names, index, name),
+        }
+    }
+
+    fn pass_name(&mut self, names: &Names, index: usize, name: &str) -> Result<(), HighsError> {
+        let c_name = c_string(name)?;
+        let (function, pass) = names.pass;
+        let status = unsafe { pass(self.as_mut_ptr(), c_len(index), c_name.as_ptr()) };
+        HighsError::check(function, status)
+    }
+
+    /// Passes the non-empty names of `all`, after checking that they are unique, also among the
+    /// names that are kept.
+    fn set_names(&mut self, names: &Names, all: &[impl AsRef<str>]) -> Result<(), HighsError> {
+        let existing = self.get_names(names)?;
+        let kept = existing
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| all.get(index).map_or(true, |name| name.as_ref().is_empty()))
+            .map(|(_, name)| name.as_str());
+        let after: Vec<&str> = all.iter().map(AsRef::as_ref).chain(kept).collect();
+        check_unique_names(&after)?;
+        for (index, name) in all.iter().enumerate() {
+            if !name.as_ref().is_empty() {
+                self.pass_name(names, index, name.as_ref())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Names the column `col`. Fails with [`HighsError::DuplicateName`] if another column has
+    /// this name, and with a HiGHS error if it is empty.
+    pub fn set_col_name(&mut self, col: usize, name: &str) -> Result<(), HighsError> {
+        self.set_name(&COLUMNS, col, name)
+    }
+
+    /// Names the row `row`, like [`Highs::set_col_name`].
+    pub fn set_row_name(&mut self, row: usize, name: &str) -> Result<(), HighsError> {
+        self.set_name(&ROWS, row, name)
+    }
+
+    /// Names the first columns, skipping empty names, whose columns keep their names. Fails with
+    /// [`HighsError::DuplicateName`] if a name is given twice or kept by another column. This
+    /// checks the uniqueness of all names at once, which is faster than
+    /// [`Highs::set_col_name`] for each column.
+    pub fn set_col_names(&mut self, names: &[impl AsRef<str>]) -> Result<(), HighsError> {
+        self.set_names(&COLUMNS, names)
+    }
+
+    /// Names the first rows, like [`Highs::set_col_names`].
+    pub fn set_row_names(&mut self, names: &[impl AsRef<str>]) -> Result<(), HighsError> {
+        self.set_names(&ROWS, names)
+    }
+
+    /// Returns the name of the column `col`. Fails if the model has no names.
+    pub fn get_col_name(&self, col: usize) -> Result<String, HighsError> {
+        self.get_name(&COLUMNS, col)
+    }
+
+    /// Returns the name of the row `row`. Fails if the model has no names.
+    pub fn get_row_name(&self, row: usize) -> Result<String, HighsError> {
+        self.get_name(&ROWS, row)
+    }
+
+    /// Returns the names of all columns, which are empty for unnamed columns, or no names if
+    /// no column has a name.
+    pub fn get_col_names(&self) -> Result<Vec<String>, HighsError> {
+        self.get_names(&COLUMNS)
+    }
+
+    /// Returns the names of all rows, like [`Highs::get_col_names`].
+    pub fn get_row_names(&self) -> Result<Vec<String>, HighsError> {
+        self.get_names(&ROWS)
+    }
+
+    /// Returns the index of the column named `name`, if there is one.
+    pub fn get_col_by_name(&self, name: &str) -> Result<Option<usize>, HighsError> {
+        self.find_name(&COLUMNS, name)
+    }
+
+    /// Returns the index of the row named `name`, if there is one.
+    pub fn get_row_by_name(&self, name: &str) -> Result<Option<usize>, HighsError> {
+        self.find_name(&ROWS, name)
+    }
+}
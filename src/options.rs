@@ -0,0 +1,226 @@
+//! Access to HiGHS options by name, whatever their type.
+
+use std::ffi::{CStr, OsString};
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::ptr::null_mut;
+
+use crate::highs::{c_len, c_string, rust_len};
+use crate::{
+    kHighsOptionTypeBool, kHighsOptionTypeDouble, kHighsOptionTypeInt, kHighsOptionTypeString,
+    Highs, HighsError, HighsInt, Highs_getNumOptions, Highs_getOptionName, Highs_getOptionType,
+};
+
+extern "C" {
+    // Frees the names allocated by `Highs_getOptionName`.
+    fn free(ptr: *mut c_void);
+}
+
+/// A variable that [`Highs::options_from_env`] or [`Highs::options_from_vars`] couldn't apply.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptionsEnvError {
+    /// The variable names no HiGHS option.
+    UnknownOption { variable: String, option: String },
+    /// The value can't be parsed with the type of the option, which is `expected`.
+    InvalidValue {
+        variable: String,
+        value: String,
+        expected: &'static str,
+    },
+    /// HiGHS rejected the value, because it is out of the range of the option for instance.
+    Rejected {
+        variable: String,
+        source: HighsError,
+    },
+}
+
+impl fmt::Display for OptionsEnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionsEnvError::UnknownOption { variable, option } => {
+                write!(f, "{}: HiGHS has no option {}", variable, option)
+            }
+            OptionsEnvError::InvalidValue {
+                variable,
+                value,
+                expected,
+            } => write!(f, "{}: {:?} is not {}", variable, value, expected),
+            OptionsEnvError::Rejected { variable, source } => write!(f, "{}: {}", variable, source),
+        }
+    }
+}
+
+impl std::error::Error for OptionsEnvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OptionsEnvError::Rejected { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The value of a HiGHS option.
+///
+/// With the `serde` feature, it is serialized with the lowercase name of its type, such as
+/// `{"double": 30.0}`, and infinite values as ±1e30.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum OptionValue {
+    Bool(bool),
+    Int(HighsInt),
+    Double(#[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::value"))] f64),
+    String(String),
+}
+
+impl Highs {
+    /// Returns the names of all options, such as `time_limit`.
+    pub fn option_names(&self) -> Result<Vec<String>, HighsError> {
+        let num_options = rust_len(unsafe { Highs_getNumOptions(self.as_ptr()) });
+        (0..num_options)
+            .map(|index| {
+                let mut name: *mut c_char = null_mut();
+                let status = unsafe { Highs_getOptionName(self.as_ptr(), c_len(index), &mut name) };
+                HighsError::check("Highs_getOptionName", status)?;
+                let owned = unsafe { CStr::from_ptr(name) }
+                    .to_string_lossy()
+                    .into_owned();
+                unsafe { free(name.cast()) };
+                Ok(owned)
+            })
+            .collect()
+    }
+
+    /// Returns the type of the option `name`, one of the `kHighsOptionType*` constants.
+    pub fn get_option_type(&self, name: &str) -> Result<HighsInt, HighsError> {
+        let c_name = c_string(name)?;
+        let mut option_type = 0;
+        let status =
+            unsafe { Highs_getOptionType(self.as_ptr(), c_name.as_ptr(), &mut option_type) };
+        HighsError::check("Highs_getOptionType", status)?;
+        Ok(option_type)
+    }
+
+    /// Returns the value of the option `name`, with the type of the option.
+    pub fn get_option(&self, name: &str) -> Result<OptionValue, HighsError> {
+        match self.get_option_type(name)? {
+            kHighsOptionTypeBool => self.get_bool_option(name).map(OptionValue::Bool),
+            kHighsOptionTypeInt => self.get_int_option(name).map(OptionValue::Int),
+            kHighsOptionTypeDouble => self.get_double_option(name).map(OptionValue::Double),
+            kHighsOptionTypeString => self.get_string_option(name).map(OptionValue::String),
+            value_type => Err(HighsError::UnknownType {
+                name: name.to_string(),
+                value_type,
+            }),
+        }
+    }
+
+    /// Sets the option `name` to `value`. HiGHS rejects values of the wrong type.
+    pub fn set_option(&mut self, name: &str, value: &OptionValue) -> Result<(), HighsError> {
+        match value {
+            OptionValue::Bool(value) => self.set_bool_option(name, *value),
+            OptionValue::Int(value) => self.set_int_option(name, *value),
+            OptionValue::Double(value) => self.set_double_option(name, *value),
+            OptionValue::String(value) => self.set_string_option(name, value),
+        }
+    }
+
+    /// Sets options from the environment variables whose names start with `prefix`, followed
+    /// by the name of an option in any case: with the prefix `HIGHS_OPT_`, the variable
+    /// `HIGHS_OPT_TIME_LIMIT=30` sets the option `time_limit` to 30.
+    ///
+    /// This reads the environment, then calls [`Highs::options_from_vars`].
+    pub fn options_from_env(&mut self, prefix: &str) -> Result<Vec<String>, OptionsEnvError> {
+        self.options_from_vars(prefix, std::env::vars_os())
+    }
+
+    /// Sets options from the `(name, value)` variables whose names start with `prefix`, like
+    /// [`Highs::options_from_env`] does with the environment variables.
+    ///
+    /// The values are parsed with the type of their option: booleans are `true`, `false`, `on`,
+    /// `off`, `1` or `0`. Returns the names of the options that were set, in the order of the
+    /// variables' names. All variables are parsed before setting any option, but if HiGHS
+    /// rejects a value, the options of the variables before it are set.
+    pub fn options_from_vars<I, K, V>(
+        &mut self,
+        prefix: &str,
+        vars: I,
+    ) -> Result<Vec<String>, OptionsEnvError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<OsString>,
+        V: Into<OsString>,
+    {
+        let mut variables: Vec<(String, OsString)> = vars
+            .into_iter()
+            .filter_map(|(name, value)| Some((name.into().into_string().ok()?, value.into())))
+            .filter(|(name, _)| name.starts_with(prefix) && name.len() > prefix.len())
+            .collect();
+        variables.sort();
+        let options = variables
+            .into_iter()
+            .map(|(variable, value)| {
+                let option = variable[prefix.len()..].to_ascii_lowercase();
+                let value = self.parse_option(&variable, &option, value)?;
+                Ok((variable, option, value))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        options
+            .into_iter()
+            .map(|(variable, option, value)| {
+                self.set_option(&option, &value)
+                    .map_err(|source| OptionsEnvError::Rejected { variable, source })?;
+                Ok(option)
+            })
+            .collect()
+    }
+
+    /// Parses the value of the `variable` with the type of `option`.
+    fn parse_option(
+        &self,
+        variable: &str,
+        option: &str,
+        value: OsString,
+    ) -> Result<OptionValue, OptionsEnvError> {
+        let option_type =
+            self.get_option_type(option)
+                .map_err(|_| OptionsEnvError::UnknownOption {
+                    variable: variable.to_string(),
+                    option: option.to_string(),
+                })?;
+        let text = value.to_str().map(str::trim);
+        let (parsed, expected) = match option_type {
+            kHighsOptionTypeBool => (
+                text.and_then(|text| match text.to_ascii_lowercase().as_str() {
+                    "true" | "on" | "1" => Some(true),
+                    "false" | "off" | "0" => Some(false),
+                    _ => None,
+                })
+                .map(OptionValue::Bool),
+                "a boolean",
+            ),
+            kHighsOptionTypeInt => (
+                text.and_then(|text| text.parse().ok())
+                    .map(OptionValue::Int),
+                "an integer",
+            ),
+            kHighsOptionTypeDouble => (
+                text.and_then(|text| text.parse().ok())
+                    .map(OptionValue::Double),
+                "a number",
+            ),
+            _ => (
+                text.map(|text| OptionValue::String(text.to_string())),
+                "valid Unicode",
+            ),
+        };
+        parsed.ok_or_else(|| OptionsEnvError::InvalidValue {
+            variable: variable.to_string(),
+            value: value.to_string_lossy().into_owned(),
+            expected,
+        })
+    }
+}
@@ -0,0 +1,91 @@
+//! Reading and writing options files with `Highs_readOptions` and `Highs_writeOptions`.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::file_path::{c_path, c_path_to_read};
+use crate::{
+    Highs, HighsInt, Highs_readOptions, Highs_writeOptions, Highs_writeOptionsDeviations,
+    PathError, STATUS_ERROR,
+};
+
+/// An error reading or writing an options file.
+#[derive(Debug)]
+pub enum OptionsFileError {
+    /// The path can't be passed to HiGHS, or the file couldn't be opened.
+    Path(PathError),
+    /// HiGHS rejected the file, because it sets an unknown option or an invalid value.
+    Read { path: PathBuf },
+    /// HiGHS couldn't write the options to the file.
+    Write { path: PathBuf },
+}
+
+impl fmt::Display for OptionsFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionsFileError::Path(error) => write!(f, "{}", error),
+            OptionsFileError::Read { path } => {
+                write!(f, "HiGHS couldn't read the options in {}", path.display())
+            }
+            OptionsFileError::Write { path } => {
+                write!(f, "HiGHS couldn't write the options to {}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for OptionsFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OptionsFileError::Path(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<PathError> for OptionsFileError {
+    fn from(error: PathError) -> Self {
+        OptionsFileError::Path(error)
+    }
+}
+
+impl Highs {
+    /// Sets the options listed in the file at `path`, with one `name = value` line per option.
+    pub fn read_options(&mut self, path: impl AsRef<Path>) -> Result<(), OptionsFileError> {
+        let path = path.as_ref();
+        let c_path = c_path_to_read(path)?;
+        let status: HighsInt = unsafe { Highs_readOptions(self.as_mut_ptr(), c_path.as_ptr()) };
+        if status == STATUS_ERROR {
+            return Err(OptionsFileError::Read { path: path.into() });
+        }
+        Ok(())
+    }
+
+    /// Writes all options to the file at `path`, in the format [`Highs::read_options`] reads,
+    /// with a comment describing each option.
+    pub fn write_options(&self, path: impl AsRef<Path>) -> Result<(), OptionsFileError> {
+        let path = path.as_ref();
+        let c_path = c_path(path)?;
+        let status: HighsInt = unsafe { Highs_writeOptions(self.as_ptr(), c_path.as_ptr()) };
+        if status == STATUS_ERROR {
+            return Err(OptionsFileError::Write { path: path.into() });
+        }
+        Ok(())
+    }
+
+    /// Writes the options that differ from their default value to the file at `path`, like
+    /// [`Highs::write_options`].
+    pub fn write_non_default_options(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), OptionsFileError> {
+        let path = path.as_ref();
+        let c_path = c_path(path)?;
+        let status: HighsInt =
+            unsafe { Highs_writeOptionsDeviations(self.as_ptr(), c_path.as_ptr()) };
+        if status == STATUS_ERROR {
+            return Err(OptionsFileError::Write { path: path.into() });
+        }
+        Ok(())
+    }
+}
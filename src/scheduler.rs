@@ -0,0 +1,116 @@
+//! Control over the task scheduler HiGHS runs its parallel algorithms on.
+//!
+//! HiGHS does not give each solver instance its own threads. The first `Highs_run` of a thread
+//! starts a scheduler with as many workers as the `threads` option of that instance, and every
+//! later run on the thread reuses it. A run whose `threads` option asks for a different number
+//! of workers fails, until `Highs_resetGlobalScheduler` shuts the scheduler down.
+//!
+//! [`set_global_scheduler_threads`] configures the worker count once per process, and every
+//! [`Highs`](crate::Highs) instance created afterwards uses it, so their runs agree.
+
+use std::convert::TryFrom;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{HighsError, HighsInt, Highs_resetGlobalScheduler};
+
+/// The worker count set by [`set_global_scheduler_threads`], or 0 to use HiGHS' default.
+static GLOBAL_SCHEDULER_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// The value of the `parallel` option, which decides whether HiGHS uses its parallel algorithms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parallel {
+    /// Always use the serial algorithms.
+    Off,
+    /// Let HiGHS choose, the default.
+    Choose,
+    /// Use the parallel algorithms where there are some.
+    On,
+}
+
+impl Parallel {
+    /// The value of the `parallel` option, e.g. `"choose"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Parallel::Off => "off",
+            Parallel::Choose => "choose",
+            Parallel::On => "on",
+        }
+    }
+}
+
+/// Sets the number of threads the HiGHS scheduler runs with, including the thread calling
+/// `Highs_run`, for the rest of the process. A `threads` of 0 restores HiGHS' default.
+/// Returns [`HighsError::TooManyThreads`], and changes nothing, if `threads` doesn't fit in a
+/// `HighsInt`.
+///
+/// This shuts down the scheduler of the calling thread, waiting for its workers to exit, so
+/// that the next run starts a new one. [`Highs`](crate::Highs) instances created afterwards
+/// get `threads` as their `threads` option. Call this before starting any solve, and not while
+/// other threads are solving.
+///
+/// # Many concurrent solves
+///
+/// To run many small models in parallel, solve each on its own thread, and give HiGHS a single
+/// thread per solve, so it starts no workers of its own on top of yours:
+///
+/// ```no_run
+/// use highs_sys::{set_global_scheduler_threads, Highs, Parallel};
+///
+/// set_global_scheduler_threads(1).unwrap();
+/// let handles: Vec<_> = (0..8)
+///     .map(|_| {
+///         std::thread::spawn(|| {
+///             let mut highs = Highs::new();
+///             highs.set_parallel(Parallel::Off).unwrap();
+///             // pass a model, e.g. with `Highs_passLp(highs.as_mut_ptr(), ...)`
+///             highs.run().unwrap();
+///             highs.objective_value()
+///         })
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// ```
+pub fn set_global_scheduler_threads(threads: usize) -> Result<(), HighsError> {
+    threads_option(threads)?;
+    GLOBAL_SCHEDULER_THREADS.store(threads, Ordering::SeqCst);
+    reset_global_scheduler(true);
+    Ok(())
+}
+
+/// Returns the last thread count requested with [`set_global_scheduler_threads`] or, by
+/// default, half the available parallelism like HiGHS: the `threads` option that new
+/// [`Highs`](crate::Highs) instances get.
+///
+/// HiGHS can't be asked how many threads its scheduler runs with, and this may not be it: a
+/// scheduler that another thread started before the last request keeps its thread count, and
+/// so does one started by an instance whose `threads` option was changed.
+pub fn global_scheduler_threads() -> usize {
+    match configured_threads() {
+        Some(threads) => threads,
+        None => {
+            let available = std::thread::available_parallelism().map_or(1, |n| n.get());
+            available.div_ceil(2)
+        }
+    }
+}
+
+/// The worker count set by [`set_global_scheduler_threads`], if any.
+pub(crate) fn configured_threads() -> Option<usize> {
+    match GLOBAL_SCHEDULER_THREADS.load(Ordering::SeqCst) {
+        0 => None,
+        threads => Some(threads),
+    }
+}
+
+/// The value of the `threads` option for `threads`.
+pub(crate) fn threads_option(threads: usize) -> Result<HighsInt, HighsError> {
+    HighsInt::try_from(threads).map_err(|_| HighsError::TooManyThreads(threads))
+}
+
+/// Shuts down the scheduler of the calling thread, so the next `Highs_run` starts a new one with
+/// the `threads` option of its instance. If `blocking`, waits for the workers to exit.
+pub fn reset_global_scheduler(blocking: bool) {
+    unsafe { Highs_resetGlobalScheduler(blocking.into()) }
+}
@@ -0,0 +1,59 @@
+//! Serialization of infinite values as ±1e30, since formats such as JSON can't represent
+//! infinity, and solvers read such large bounds as infinite.
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// The magnitude of serialized infinite values. Larger values are deserialized as infinite too.
+const INFINITY: f64 = 1e30;
+
+fn encode(value: f64) -> f64 {
+    if value == f64::INFINITY {
+        INFINITY
+    } else if value == f64::NEG_INFINITY {
+        -INFINITY
+    } else {
+        value
+    }
+}
+
+fn decode(value: f64) -> f64 {
+    if value >= INFINITY {
+        f64::INFINITY
+    } else if value <= -INFINITY {
+        f64::NEG_INFINITY
+    } else {
+        value
+    }
+}
+
+/// For `#[serde(with = "...")]` on `f64` fields.
+pub(crate) mod value {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(encode(*value))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        f64::deserialize(deserializer).map(decode)
+    }
+}
+
+/// For `#[serde(with = "...")]` on `Vec<f64>` fields.
+pub(crate) mod vec {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        values: &[f64],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(|&value| encode(value)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<f64>, D::Error> {
+        let values = Vec::<f64>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(decode).collect())
+    }
+}
@@ -0,0 +1,206 @@
+//! Owned copies of the solution and basis of a HiGHS instance.
+
+use crate::highs::{c_string, rust_len};
+use crate::{
+    kHighsBasisValidityValid, kHighsInfoTypeDouble, kHighsInfoTypeInt, kHighsInfoTypeInt64, Highs,
+    HighsError, HighsInt, Highs_getBasis, Highs_getDoubleInfoValue, Highs_getInfoType,
+    Highs_getInt64InfoValue, Highs_getIntInfoValue, Highs_getNumCol, Highs_getNumRow,
+    Highs_getSolution, Highs_setBasis, Highs_setSolution, SOLUTION_STATUS_NONE,
+};
+
+/// The primal and dual values of the columns and rows of a model.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Solution {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::vec"))]
+    pub col_value: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::vec"))]
+    pub col_dual: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::vec"))]
+    pub row_value: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::vec"))]
+    pub row_dual: Vec<f64>,
+}
+
+/// The basis status of each column and row of a model, one of the `kHighsBasisStatus*`
+/// constants, such as `kHighsBasisStatusBasic`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Basis {
+    pub col_status: Vec<HighsInt>,
+    pub row_status: Vec<HighsInt>,
+}
+
+/// The value of a HiGHS info, which describes the last run.
+///
+/// With the `serde` feature, it is serialized like [`OptionValue`](crate::OptionValue).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum InfoValue {
+    Int(HighsInt),
+    Int64(i64),
+    Double(#[cfg_attr(feature = "serde", serde(with = "crate::serde_infinity::value"))] f64),
+}
+
+impl Highs {
+    /// The number of columns and rows of the model.
+    fn dimensions(&self) -> (usize, usize) {
+        unsafe {
+            (
+                rust_len(Highs_getNumCol(self.as_ptr())),
+                rust_len(Highs_getNumRow(self.as_ptr())),
+            )
+        }
+    }
+
+    /// Returns the value of the integer info `name`, such as `simplex_iteration_count`.
+    /// Info values are only available after [`Highs::run`].
+    pub fn get_int_info(&self, name: &str) -> Result<HighsInt, HighsError> {
+        let name = c_string(name)?;
+        let mut value = 0;
+        let status = unsafe { Highs_getIntInfoValue(self.as_ptr(), name.as_ptr(), &mut value) };
+        HighsError::check("Highs_getIntInfoValue", status)?;
+        Ok(value)
+    }
+
+    /// Returns the value of the info `name`, such as `objective_function_value`, with the type of
+    /// the info.
+    pub fn get_info(&self, name: &str) -> Result<InfoValue, HighsError> {
+        let c_name = c_string(name)?;
+        let mut info_type = 0;
+        let status = unsafe { Highs_getInfoType(self.as_ptr(), c_name.as_ptr(), &mut info_type) };
+        HighsError::check("Highs_getInfoType", status)?;
+        match info_type {
+            kHighsInfoTypeInt => self.get_int_info(name).map(InfoValue::Int),
+            kHighsInfoTypeInt64 => {
+                let mut value = 0;
+                let status =
+                    unsafe { Highs_getInt64InfoValue(self.as_ptr(), c_name.as_ptr(), &mut value) };
+                HighsError::check("Highs_getInt64InfoValue", status)?;
+                Ok(InfoValue::Int64(value))
+            }
+            kHighsInfoTypeDouble => {
+                let mut value = 0.;
+                let status =
+                    unsafe { Highs_getDoubleInfoValue(self.as_ptr(), c_name.as_ptr(), &mut value) };
+                HighsError::check("Highs_getDoubleInfoValue", status)?;
+                Ok(InfoValue::Double(value))
+            }
+            value_type => Err(HighsError::UnknownType {
+                name: name.to_string(),
+                value_type,
+            }),
+        }
+    }
+
+    /// Whether the last run found a primal solution, feasible or not.
+    pub fn has_solution(&self) -> bool {
+        self.get_int_info("primal_solution_status")
+            .is_ok_and(|status| status != SOLUTION_STATUS_NONE)
+    }
+
+    /// Whether the instance has a valid basis, from a simplex run or [`Highs::set_basis`].
+    pub fn has_basis(&self) -> bool {
+        self.get_int_info("basis_validity")
+            .is_ok_and(|validity| validity == kHighsBasisValidityValid)
+    }
+
+    /// Returns the current solution. Its values are meaningless unless [`Highs::has_solution`].
+    pub fn get_solution(&self) -> Result<Solution, HighsError> {
+        let (num_col, num_row) = self.dimensions();
+        let mut solution = Solution {
+            col_value: vec![0.; num_col],
+            col_dual: vec![0.; num_col],
+            row_value: vec![0.; num_row],
+            row_dual: vec![0.; num_row],
+        };
+        let status = unsafe {
+            Highs_getSolution(
+                self.as_ptr(),
+                solution.col_value.as_mut_ptr(),
+                solution.col_dual.as_mut_ptr(),
+                solution.row_value.as_mut_ptr(),
+                solution.row_dual.as_mut_ptr(),
+            )
+        };
+        HighsError::check("Highs_getSolution", status)?;
+        Ok(solution)
+    }
+
+    /// Sets the solution, for instance as a starting point for the MIP solver.
+    pub fn set_solution(&mut self, solution: &Solution) -> Result<(), HighsError> {
+        self.check_dimensions(
+            "solution",
+            solution.col_value.len(),
+            solution.row_value.len(),
+        )?;
+        self.check_dimensions(
+            "solution duals",
+            solution.col_dual.len(),
+            solution.row_dual.len(),
+        )?;
+        let status = unsafe {
+            Highs_setSolution(
+                self.as_mut_ptr(),
+                solution.col_value.as_ptr(),
+                solution.row_value.as_ptr(),
+                solution.col_dual.as_ptr(),
+                solution.row_dual.as_ptr(),
+            )
+        };
+        HighsError::check("Highs_setSolution", status)
+    }
+
+    /// Returns the current basis. Its statuses are meaningless unless [`Highs::has_basis`].
+    pub fn get_basis(&self) -> Result<Basis, HighsError> {
+        let (num_col, num_row) = self.dimensions();
+        let mut basis = Basis {
+            col_status: vec![0; num_col],
+            row_status: vec![0; num_row],
+        };
+        let status = unsafe {
+            Highs_getBasis(
+                self.as_ptr(),
+                basis.col_status.as_mut_ptr(),
+                basis.row_status.as_mut_ptr(),
+            )
+        };
+        HighsError::check("Highs_getBasis", status)?;
+        Ok(basis)
+    }
+
+    /// Sets the basis the next simplex run starts from.
+    pub fn set_basis(&mut self, basis: &Basis) -> Result<(), HighsError> {
+        self.check_dimensions("basis", basis.col_status.len(), basis.row_status.len())?;
+        let status = unsafe {
+            Highs_setBasis(
+                self.as_mut_ptr(),
+                basis.col_status.as_ptr(),
+                basis.row_status.as_ptr(),
+            )
+        };
+        HighsError::check("Highs_setBasis", status)
+    }
+
+    /// Returns [`HighsError::DimensionMismatch`] unless `num_col` and `num_row` are the
+    /// dimensions of the model, as HiGHS would otherwise read past the end of the arrays.
+    fn check_dimensions(
+        &self,
+        what: &str,
+        num_col: usize,
+        num_row: usize,
+    ) -> Result<(), HighsError> {
+        let dimensions = self.dimensions();
+        if (num_col, num_row) == dimensions {
+            return Ok(());
+        }
+        Err(HighsError::DimensionMismatch(format!(
+            "the {} has {} columns and {} rows, but the model has {} columns and {} rows",
+            what, num_col, num_row, dimensions.0, dimensions.1
+        )))
+    }
+}
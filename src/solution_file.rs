@@ -0,0 +1,187 @@
+//! Reading and writing solution files with `Highs_readSolution` and `Highs_writeSolution`, and
+//! a parser of these files.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::file_path::{c_path, c_path_to_read};
+use crate::{
+    Highs, HighsInt, Highs_readSolution, Highs_writeSolution, Highs_writeSolutionPretty, PathError,
+    Solution, STATUS_ERROR,
+};
+
+/// An error reading or writing a solution file.
+#[derive(Debug)]
+pub enum SolutionFileError {
+    /// The path can't be passed to HiGHS, or the file couldn't be opened.
+    Path(PathError),
+    /// HiGHS couldn't read a solution for the current model from the file.
+    Read { path: PathBuf },
+    /// HiGHS couldn't write the solution to the file.
+    Write { path: PathBuf },
+    /// The content is not a solution file in HiGHS' format, at a 1-based `line`.
+    Parse { line: usize, expected: &'static str },
+}
+
+impl fmt::Display for SolutionFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolutionFileError::Path(error) => write!(f, "{}", error),
+            SolutionFileError::Read { path } => {
+                write!(f, "HiGHS couldn't read a solution from {}", path.display())
+            }
+            SolutionFileError::Write { path } => {
+                write!(f, "HiGHS couldn't write the solution to {}", path.display())
+            }
+            SolutionFileError::Parse { line, expected } => {
+                write!(f, "line {}: expected {}", line, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolutionFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SolutionFileError::Path(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<PathError> for SolutionFileError {
+    fn from(error: PathError) -> Self {
+        SolutionFileError::Path(error)
+    }
+}
+
+impl Highs {
+    /// Writes the solution and basis to the file at `path`, in the format that
+    /// [`Highs::read_solution`] and [`Solution::from_solution_file`] read.
+    pub fn write_solution(&self, path: impl AsRef<Path>) -> Result<(), SolutionFileError> {
+        let path = path.as_ref();
+        let c_path = c_path(path)?;
+        let status: HighsInt = unsafe { Highs_writeSolution(self.as_ptr(), c_path.as_ptr()) };
+        if status == STATUS_ERROR {
+            return Err(SolutionFileError::Write { path: path.into() });
+        }
+        Ok(())
+    }
+
+    /// Writes the solution to the file at `path` as tables meant for people, which can't be read
+    /// back.
+    pub fn write_solution_pretty(&self, path: impl AsRef<Path>) -> Result<(), SolutionFileError> {
+        let path = path.as_ref();
+        let c_path = c_path(path)?;
+        let status: HighsInt = unsafe { Highs_writeSolutionPretty(self.as_ptr(), c_path.as_ptr()) };
+        if status == STATUS_ERROR {
+            return Err(SolutionFileError::Write { path: path.into() });
+        }
+        Ok(())
+    }
+
+    /// Replaces the solution with the one in the file at `path`, written by
+    /// [`Highs::write_solution`] for the same model. Before solving a MIP, the solution is used
+    /// as a start.
+    pub fn read_solution(&mut self, path: impl AsRef<Path>) -> Result<(), SolutionFileError> {
+        let path = path.as_ref();
+        let c_path = c_path_to_read(path)?;
+        let status: HighsInt = unsafe { Highs_readSolution(self.as_mut_ptr(), c_path.as_ptr()) };
+        if status == STATUS_ERROR {
+            return Err(SolutionFileError::Read { path: path.into() });
+        }
+        Ok(())
+    }
+}
+
+/// The non-blank lines of a file, trimmed, with their 1-based line numbers.
+struct Lines<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    line: usize,
+}
+
+impl<'a> Lines<'a> {
+    fn next(&mut self, expected: &'static str) -> Result<&'a str, SolutionFileError> {
+        for (index, line) in &mut self.lines {
+            self.line = index + 1;
+            if !line.trim().is_empty() {
+                return Ok(line.trim());
+            }
+        }
+        Err(self.error(expected))
+    }
+
+    fn error(&self, expected: &'static str) -> SolutionFileError {
+        SolutionFileError::Parse {
+            line: self.line,
+            expected,
+        }
+    }
+
+    fn expect(&mut self, header: &'static str) -> Result<(), SolutionFileError> {
+        if self.next(header)? != header {
+            return Err(self.error(header));
+        }
+        Ok(())
+    }
+
+    /// Reads a `header` line with a count, then as many lines ending with a value.
+    fn values(&mut self, header: &'static str) -> Result<Vec<f64>, SolutionFileError> {
+        let count: usize = self
+            .next(header)?
+            .strip_prefix(header)
+            .and_then(|count| count.trim().parse().ok())
+            .ok_or_else(|| self.error(header))?;
+        (0..count)
+            .map(|_| {
+                // The value follows the name, if the model has names.
+                self.next("a value")?
+                    .split_whitespace()
+                    .last()
+                    .and_then(|value| value.parse().ok())
+                    .ok_or_else(|| self.error("a value"))
+            })
+            .collect()
+    }
+
+    /// Reads the status of a primal or dual solution and, unless it is `None`, the values of
+    /// its columns and rows.
+    fn solution_values(
+        &mut self,
+        primal: bool,
+        col_values: &mut Vec<f64>,
+        row_values: &mut Vec<f64>,
+    ) -> Result<(), SolutionFileError> {
+        match self.next("a solution status")? {
+            "None" => return Ok(()),
+            "Feasible" | "Infeasible" => (),
+            _ => return Err(self.error("a solution status")),
+        }
+        if primal && !self.next("Objective")?.starts_with("Objective") {
+            return Err(self.error("Objective"));
+        }
+        *col_values = self.values("# Columns")?;
+        *row_values = self.values("# Rows")?;
+        Ok(())
+    }
+}
+
+impl Solution {
+    /// Parses a solution file written by [`Highs::write_solution`], without a solver instance.
+    ///
+    /// The vectors of a primal or dual solution the file marks as `None` are empty. The basis
+    /// at the end of the file is ignored.
+    pub fn from_solution_file(content: &str) -> Result<Solution, SolutionFileError> {
+        let mut lines = Lines {
+            lines: content.lines().enumerate(),
+            line: 0,
+        };
+        // Skip the model status.
+        while lines.next("# Primal solution values")? != "# Primal solution values" {}
+        let mut solution = Solution::default();
+        lines.solution_values(true, &mut solution.col_value, &mut solution.row_value)?;
+        lines.expect("# Dual solution values")?;
+        lines.solution_values(false, &mut solution.col_dual, &mut solution.row_dual)?;
+        Ok(solution)
+    }
+}
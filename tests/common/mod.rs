@@ -0,0 +1,62 @@
+//! Helpers shared by the integration tests, each of which uses some of them.
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use highs_sys::*;
+
+/// The path of a file in `tests/fixtures`.
+pub fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+/// The content of a file in `tests/fixtures`.
+pub fn read_fixture(name: &str) -> String {
+    fs::read_to_string(fixture(name)).unwrap()
+}
+
+/// A path for a file written by a test, in the temporary directory of the integration tests.
+pub fn temp_file(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+/// A solver instance that logs nothing.
+pub fn quiet_highs() -> Highs {
+    let mut highs = Highs::new();
+    highs.set_bool_option("output_flag", false).unwrap();
+    highs
+}
+
+/// Owned copies of `names`, for the `col_names` and `row_names` of a `Model`.
+pub fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+/// The LP from `test_highs_functions.rs`
+/// Max    f  = 2x_0 + 3x_1
+/// s.t.                x_1 <= 6
+///       10 <=  x_0 + 2x_1 <= 14
+///        8 <= 2x_0 +  x_1
+/// 0 <= x_0 <= 3; 1 <= x_1
+/// whose optimum is x = (3, 5.5), with an objective of 22.5.
+pub fn small_lp() -> Model {
+    let inf = f64::INFINITY;
+    Model {
+        sense: OBJECTIVE_SENSE_MAXIMIZE,
+        col_cost: vec![2.0, 3.0],
+        col_lower: vec![0.0, 1.0],
+        col_upper: vec![3.0, inf],
+        row_lower: vec![-inf, 10.0, 8.0],
+        row_upper: vec![6.0, 14.0, inf],
+        a_matrix: SparseMatrix {
+            start: vec![0, 2],
+            index: vec![1, 2, 0, 1, 2],
+            value: vec![1.0, 2.0, 1.0, 2.0, 1.0],
+        },
+        ..Model::default()
+    }
+}
@@ -0,0 +1,20 @@
+NAME        highs_call
+ROWS
+ N  COST
+ L  R0
+ G  R1
+ G  R2
+COLUMNS
+    X0        COST      2              R1        1
+    X0        R2        2
+    X1        COST      3              R0        1
+    X1        R1        2              R2        1
+RHS
+    RHS       R0        6              R1        10
+    RHS       R2        8
+RANGES
+    RNG       R1        4
+BOUNDS
+ UP BND       X0        3
+ LO BND       X1        1
+ENDATA
@@ -0,0 +1,31 @@
+Model status
+Optimal
+
+# Primal solution values
+Feasible
+Objective 16
+# Columns 2
+X0 2
+X1 4
+# Rows 3
+R0 4
+R1 10
+R2 8
+
+# Dual solution values
+Feasible
+# Columns 2
+X0 0
+X1 0
+# Rows 3
+R0 0
+R1 1.3333333333333333
+R2 0.33333333333333331
+
+# Basis
+HiGHS v1
+Valid
+# Columns 2
+1 1
+# Rows 3
+1 0 0
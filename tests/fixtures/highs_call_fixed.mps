@@ -0,0 +1,21 @@
+NAME          highs call fixed
+* The LP of highs_call.mps in fixed MPS, with spaces in names.
+ROWS
+ N  COST
+ L  ROW 0
+ G  ROW 1
+ G  ROW 2
+COLUMNS
+    X 0       COST                 2   ROW 1                1
+    X 0       ROW 2                2
+    X 1       COST                 3   ROW 0                1
+    X 1       ROW 1                2   ROW 2                1
+RHS
+    RHS       ROW 0                6   ROW 1               10
+    RHS       ROW 2                8
+RANGES
+    RNG       ROW 1                4
+BOUNDS
+ UP BND       X 0                  3
+ LO BND       X 1                  1
+ENDATA
@@ -0,0 +1,37 @@
+NAME          mip_features
+* Free MPS with the features of the native reader: objective sense, ranges,
+* integer markers, semi-continuous and binary bounds, and a dropped N row.
+OBJSENSE
+    MAX
+ROWS
+ N  profit
+ E  balance
+ L  cap
+ G  demand
+ E  link
+ N  unused
+COLUMNS
+    MARKER  'MARKER'  'INTORG'
+    x  profit  1  cap  1
+    x  balance  1
+    MARKER  'MARKER'  'INTEND'
+    y  profit  2  balance  -1
+    y  demand  1
+    z  profit  -1  cap  2
+    z  link  1  unused  7
+    s  profit  0.5  demand  1
+    b  profit  3  cap  1
+RHS
+    profit  -10  cap  20
+    demand  2  link  1
+RANGES
+    rng  cap  5  demand  -4
+    rng  link  2
+BOUNDS
+ UP bnd  x  4
+ MI bnd  y
+ UP bnd  y  8
+ SC bnd  s  6
+ LO bnd  s  1
+ BV bnd  b
+ENDATA
@@ -0,0 +1,17 @@
+NAME          qp
+ROWS
+ N  obj
+ L  c1
+COLUMNS
+    x0  obj  -3  c1  1
+    x1  c1  1
+RHS
+    rhs  c1  2
+BOUNDS
+ FR bnd  x0
+ FR bnd  x1
+QUADOBJ
+    x0  x0  2
+    x0  x1  -1
+    x1  x1  2
+ENDATA
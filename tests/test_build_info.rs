@@ -0,0 +1,29 @@
+use std::ffi::CStr;
+
+use highs_sys::*;
+
+#[test]
+fn build_info_matches_linked_library() {
+    let info = build_info();
+    let version = unsafe { CStr::from_ptr(Highs_version()) };
+    assert_eq!(info.version, version.to_str().unwrap());
+    assert_eq!(info.highs_int_bits, 8 * std::mem::size_of::<HighsInt>());
+    assert_eq!(info.compilation_date.len(), "YYYY-MM-DD".len());
+    assert!(info
+        .to_string()
+        .starts_with(&format!("HiGHS {}", info.version)));
+}
+
+#[cfg(not(feature = "discover"))]
+#[test]
+fn build_info_of_bundled_build() {
+    let info = build_info();
+    assert!(info.static_link);
+    assert_ne!(info.source, HighsSource::Discovered);
+    assert_eq!(info.zlib, cfg!(feature = "libz"));
+    if cfg!(feature = "highs_debug") {
+        assert_eq!(info.profile, Some("Debug"));
+    } else if cfg!(feature = "highs_release") && info.source == HighsSource::Bundled {
+        assert_eq!(info.profile, Some("Release"));
+    }
+}
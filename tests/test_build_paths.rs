@@ -0,0 +1,137 @@
+//! Checks that the CMake (`build`) and `cc` (`build-cc`) builds of HiGHS behave the same. Each
+//! run records its results in the target directory and compares them with those of the other
+//! build of the same version, if it ran before. CI runs this test with both features, and sets
+//! `HIGHS_SYS_COMPARE_BUILD_PATHS` on the second run so that a missing record fails it.
+#![cfg(all(
+    any(feature = "build", feature = "build-cc"),
+    not(feature = "discover")
+))]
+
+use std::convert::TryInto;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use highs_sys::*;
+
+/// The build path of this run: the build script prefers `cc` if both features are enabled.
+const BUILD_PATH: &str = if cfg!(feature = "build-cc") {
+    "cc"
+} else {
+    "cmake"
+};
+const OTHER_BUILD_PATH: &str = if cfg!(feature = "build-cc") {
+    "cmake"
+} else {
+    "cc"
+};
+
+fn record_path(build_path: &str) -> PathBuf {
+    Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("build_paths")
+        .join(format!("{}.txt", build_path))
+}
+
+/// Solves the `highs_call` LP, checks the solution, and returns the exact results.
+fn solve_highs_call_lp() -> String {
+    // The LP from `test_highs_call.rs`:
+    // Min    f  = 2x_0 + 3x_1
+    // s.t.                x_1 <= 6
+    //       10 <=  x_0 + 2x_1 <= 14
+    //        8 <= 2x_0 +  x_1
+    // 0 <= x_0 <= 3; 1 <= x_1
+    let numcol: usize = 2;
+    let numrow: usize = 3;
+    let nnz: usize = 5;
+
+    let colcost: &[f64] = &[2.0, 3.0];
+    let collower: &[f64] = &[0.0, 1.0];
+    let colupper: &[f64] = &[3.0, 1.0e30];
+    let rowlower: &[f64] = &[-1.0e30, 10.0, 8.0];
+    let rowupper: &[f64] = &[6.0, 14.0, 1.0e30];
+    let astart: &[HighsInt] = &[0, 2];
+    let aindex: &[HighsInt] = &[1, 2, 0, 1, 2];
+    let avalue: &[f64] = &[1.0, 2.0, 1.0, 2.0, 1.0];
+
+    let colvalue: &mut [f64] = &mut vec![0.; numcol];
+    let coldual: &mut [f64] = &mut vec![0.; numcol];
+    let rowvalue: &mut [f64] = &mut vec![0.; numrow];
+    let rowdual: &mut [f64] = &mut vec![0.; numrow];
+    let colbasisstatus: &mut [HighsInt] = &mut vec![0; numcol];
+    let rowbasisstatus: &mut [HighsInt] = &mut vec![0; numrow];
+    let modelstatus: &mut HighsInt = &mut 0;
+
+    let status: HighsInt = unsafe {
+        Highs_lpCall(
+            numcol.try_into().unwrap(),
+            numrow.try_into().unwrap(),
+            nnz.try_into().unwrap(),
+            MATRIX_FORMAT_COLUMN_WISE,
+            OBJECTIVE_SENSE_MINIMIZE,
+            0.0,
+            colcost.as_ptr(),
+            collower.as_ptr(),
+            colupper.as_ptr(),
+            rowlower.as_ptr(),
+            rowupper.as_ptr(),
+            astart.as_ptr(),
+            aindex.as_ptr(),
+            avalue.as_ptr(),
+            colvalue.as_mut_ptr(),
+            coldual.as_mut_ptr(),
+            rowvalue.as_mut_ptr(),
+            rowdual.as_mut_ptr(),
+            colbasisstatus.as_mut_ptr(),
+            rowbasisstatus.as_mut_ptr(),
+            modelstatus,
+        )
+    };
+
+    assert_eq!(status, STATUS_OK);
+    assert_eq!(*modelstatus, MODEL_STATUS_OPTIMAL);
+    assert_eq!(colvalue, &[2., 4.]);
+    assert_eq!(rowvalue, &[4., 10., 8.]);
+    let expected_rowdual = [0., 4. / 3., 1. / 3.];
+    for (dual, expected) in rowdual.iter().zip(expected_rowdual) {
+        assert!((dual - expected).abs() < 1e-9, "{:?}", rowdual);
+    }
+    assert!(
+        coldual.iter().all(|dual| dual.abs() < 1e-9),
+        "{:?}",
+        coldual
+    );
+    format!(
+        "status {}\nmodel status {}\ncol values {:?}\ncol duals {:?}\nrow values {:?}\nrow duals {:?}\ncol basis {:?}\nrow basis {:?}\n",
+        status, modelstatus, colvalue, coldual, rowvalue, rowdual, colbasisstatus, rowbasisstatus
+    )
+}
+
+#[test]
+fn same_results_as_other_build_path() {
+    let info = build_info();
+    if info.source != HighsSource::Bundled {
+        // HIGHS_PREBUILT_DIR bypasses both build paths.
+        return;
+    }
+    // The cc build doesn't know the git hash of the sources, so only the version is compared.
+    let record = format!("HiGHS {}\n{}", info.version, solve_highs_call_lp());
+    let path = record_path(BUILD_PATH);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, &record).unwrap();
+
+    match fs::read_to_string(record_path(OTHER_BUILD_PATH)) {
+        Ok(other) if other.lines().next() == record.lines().next() => assert_eq!(
+            record, other,
+            "the {} and {} builds differ",
+            BUILD_PATH, OTHER_BUILD_PATH
+        ),
+        _ if env::var_os("HIGHS_SYS_COMPARE_BUILD_PATHS").is_some() => panic!(
+            "no results of the {} build of HiGHS {} to compare with",
+            OTHER_BUILD_PATH, info.version
+        ),
+        _ => eprintln!(
+            "no results of the {} build of HiGHS {} to compare with",
+            OTHER_BUILD_PATH, info.version
+        ),
+    }
+}
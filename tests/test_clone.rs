@@ -0,0 +1,111 @@
+mod common;
+
+use common::{quiet_highs, small_lp};
+use highs_sys::*;
+
+/// The shared small LP, solved, whose optimum is x = (3, 5.5).
+fn solved_lp() -> Highs {
+    let mut highs = quiet_highs();
+    highs.pass_model(&small_lp()).unwrap();
+    highs.set_double_option("time_limit", 100.).unwrap();
+    highs.run().unwrap();
+    assert_eq!(highs.model_status(), MODEL_STATUS_OPTIMAL);
+    highs
+}
+
+#[test]
+fn clone_copies_model_options_solution_and_basis() {
+    let original = solved_lp();
+    let clone = original.try_clone().unwrap();
+
+    assert_eq!(
+        clone.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap(),
+        original.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap()
+    );
+    assert_eq!(
+        clone.get_option("time_limit").unwrap(),
+        OptionValue::Double(100.)
+    );
+    assert_eq!(
+        clone.get_option("output_flag").unwrap(),
+        OptionValue::Bool(false)
+    );
+    assert_eq!(
+        clone.get_solution().unwrap(),
+        original.get_solution().unwrap()
+    );
+    assert!(clone.has_basis());
+    assert_eq!(clone.get_basis().unwrap(), original.get_basis().unwrap());
+}
+
+#[test]
+fn clone_restarts_from_the_copied_basis() {
+    let original = solved_lp();
+    let mut clone = original.clone();
+    clone.run().unwrap();
+    assert_eq!(clone.model_status(), MODEL_STATUS_OPTIMAL);
+    assert_eq!(clone.get_int_info("simplex_iteration_count").unwrap(), 0);
+    assert_eq!(clone.objective_value(), original.objective_value());
+}
+
+#[test]
+fn modifying_the_clone_leaves_the_original_unchanged() {
+    let original = solved_lp();
+    let model = original.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap();
+    let solution = original.get_solution().unwrap();
+    let objective = original.objective_value();
+
+    let mut clone = original.clone();
+    clone.set_double_option("time_limit", 5.).unwrap();
+    assert_eq!(
+        unsafe { Highs_changeColCost(clone.as_mut_ptr(), 0, -2.0) },
+        STATUS_OK
+    );
+    clone.run().unwrap();
+    assert_eq!(clone.model_status(), MODEL_STATUS_OPTIMAL);
+    assert_ne!(clone.objective_value(), objective);
+
+    assert_eq!(
+        original.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap(),
+        model
+    );
+    assert_eq!(original.get_solution().unwrap(), solution);
+    assert_eq!(original.objective_value(), objective);
+    assert_eq!(original.model_status(), MODEL_STATUS_OPTIMAL);
+    assert_eq!(
+        original.get_option("time_limit").unwrap(),
+        OptionValue::Double(100.)
+    );
+}
+
+#[test]
+fn solutions_and_bases_of_other_dimensions_are_rejected() {
+    let mut highs = solved_lp();
+    let mut solution = highs.get_solution().unwrap();
+    solution.col_value.pop();
+    assert_eq!(
+        highs.set_solution(&solution).unwrap_err().to_string(),
+        "the solution has 1 columns and 3 rows, but the model has 2 columns and 3 rows"
+    );
+    let mut basis = highs.get_basis().unwrap();
+    basis.row_status.push(kHighsBasisStatusBasic);
+    assert!(matches!(
+        highs.set_basis(&basis),
+        Err(HighsError::DimensionMismatch(_))
+    ));
+}
+
+#[test]
+fn option_names_and_types() {
+    let highs = Highs::new();
+    let names = highs.option_names().unwrap();
+    assert!(names.iter().any(|name| name == "time_limit"));
+    assert_eq!(
+        highs.get_option_type("time_limit").unwrap(),
+        kHighsOptionTypeDouble
+    );
+    assert_eq!(
+        highs.get_option("solver").unwrap(),
+        OptionValue::String("choose".to_string())
+    );
+}
@@ -0,0 +1,151 @@
+mod common;
+
+use common::quiet_highs;
+use highs_sys::*;
+
+#[test]
+fn triangular_triplets_are_normalized_to_the_lower_triangle() {
+    // Upper triangle entries, out of order, with a duplicate and an explicit zero.
+    let hessian = Hessian::from_triangular_triplets(
+        3,
+        &[
+            (0, 2, 1.0),
+            (1, 1, 4.0),
+            (0, 0, 1.0),
+            (0, 0, 1.0),
+            (1, 2, 0.0),
+        ],
+    )
+    .unwrap();
+    assert_eq!(hessian.dim(), 3);
+    assert_eq!(
+        hessian.matrix(),
+        &SparseMatrix {
+            start: vec![0, 2, 3],
+            index: vec![0, 2, 1],
+            value: vec![2.0, 1.0, 4.0],
+        }
+    );
+
+    let lower = Hessian::from_triangular_triplets(3, &[(2, 0, 1.0), (1, 1, 4.0), (0, 0, 2.0)]);
+    assert_eq!(lower.unwrap(), hessian);
+}
+
+#[test]
+fn symmetric_triplets_keep_one_triangle() {
+    let hessian = Hessian::from_symmetric_triplets(
+        2,
+        &[(0, 0, 2.0), (0, 1, -1.0), (1, 0, -1.0), (1, 1, 2.0)],
+    )
+    .unwrap();
+    assert_eq!(
+        hessian.matrix(),
+        &SparseMatrix {
+            start: vec![0, 2],
+            index: vec![0, 1, 1],
+            value: vec![2.0, -1.0, 2.0],
+        }
+    );
+}
+
+#[test]
+fn invalid_triplets_are_rejected() {
+    assert_eq!(
+        Hessian::from_symmetric_triplets(2, &[(0, 1, -1.0), (1, 0, -2.0)]),
+        Err(HessianError::Asymmetric { row: 0, col: 1 })
+    );
+    assert_eq!(
+        Hessian::from_symmetric_triplets(2, &[(0, 1, -1.0)]),
+        Err(HessianError::Asymmetric { row: 0, col: 1 })
+    );
+    assert_eq!(
+        Hessian::from_triangular_triplets(2, &[(2, 0, 1.0)]),
+        Err(HessianError::IndexOutOfRange {
+            row: 2,
+            col: 0,
+            dim: 2
+        })
+    );
+    assert_eq!(
+        Hessian::from_triangular_triplets(2, &[(1, 1, f64::NAN)]),
+        Err(HessianError::NotFinite { row: 1, col: 1 })
+    );
+}
+
+#[test]
+fn positive_semidefinite_check() {
+    let definite = Hessian::from_triangular_triplets(2, &[(0, 0, 2.0), (1, 0, -1.0), (1, 1, 2.0)]);
+    assert_eq!(definite.unwrap().check_positive_semidefinite(1e-9), Ok(()));
+
+    // [[1, 1], [1, 1]] is singular but semidefinite, and so is a zero column.
+    let singular = Hessian::from_triangular_triplets(3, &[(0, 0, 1.0), (1, 0, 1.0), (1, 1, 1.0)]);
+    assert_eq!(singular.unwrap().check_positive_semidefinite(1e-9), Ok(()));
+
+    // [[1, 2], [2, 1]] has the eigenvalue -1.
+    let indefinite = Hessian::from_triangular_triplets(2, &[(0, 0, 1.0), (1, 0, 2.0), (1, 1, 1.0)]);
+    assert_eq!(
+        indefinite.unwrap().check_positive_semidefinite(1e-9),
+        Err(HessianError::NotPositiveSemidefinite)
+    );
+
+    // An off-diagonal entry with a zero diagonal cannot be semidefinite.
+    let zero_diagonal = Hessian::from_triangular_triplets(2, &[(1, 0, 1.0)]);
+    assert_eq!(
+        zero_diagonal.unwrap().check_positive_semidefinite(1e-9),
+        Err(HessianError::NotPositiveSemidefinite)
+    );
+
+    let large = Hessian::from_triangular_triplets(MAX_SEMIDEFINITE_CHECK_DIM + 1, &[]).unwrap();
+    assert_eq!(
+        large.check_positive_semidefinite(1e-9),
+        Err(HessianError::TooLarge {
+            dim: MAX_SEMIDEFINITE_CHECK_DIM + 1
+        })
+    );
+}
+
+#[test]
+fn solve_convex_qp() {
+    // Min    f  = x_0^2 - x_0 x_1 + x_1^2 - 3x_0
+    // s.t.   x_0 + x_1 <= 2
+    // whose optimum is x = (1.5, 0.5), with f = -2.75.
+    let hessian = Hessian::from_symmetric_triplets(
+        2,
+        &[(0, 0, 2.0), (0, 1, -1.0), (1, 0, -1.0), (1, 1, 2.0)],
+    )
+    .unwrap();
+    hessian.check_positive_semidefinite(1e-9).unwrap();
+
+    let mut highs = quiet_highs();
+    let inf = unsafe { Highs_getInfinity(highs.as_ptr()) };
+    highs
+        .pass_model(&Model {
+            col_cost: vec![-3.0, 0.0],
+            col_lower: vec![-inf, -inf],
+            col_upper: vec![inf, inf],
+            row_lower: vec![-inf],
+            row_upper: vec![2.0],
+            a_matrix: SparseMatrix {
+                start: vec![0, 1],
+                index: vec![0, 0],
+                value: vec![1.0, 1.0],
+            },
+            ..Model::default()
+        })
+        .unwrap();
+    highs.pass_hessian(&hessian).unwrap();
+    assert_eq!(
+        highs.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap().hessian,
+        SparseMatrix::from(hessian)
+    );
+
+    highs.run().unwrap();
+    assert_eq!(highs.model_status(), MODEL_STATUS_OPTIMAL);
+    assert!((highs.objective_value() + 2.75).abs() < 1e-6);
+    let x = highs.get_solution().unwrap().col_value;
+    assert!(
+        (x[0] - 1.5).abs() < 1e-6 && (x[1] - 0.5).abs() < 1e-6,
+        "x = {:?}",
+        x
+    );
+}
@@ -0,0 +1,42 @@
+//! The HiPO interior point solver is only built with the `hipo` feature.
+#![cfg(feature = "hipo")]
+
+mod common;
+
+use common::{quiet_highs, small_lp};
+use highs_sys::*;
+
+/// Solves the shared small LP with the given `solver` option, and returns the objective value
+/// and the column values.
+fn solve_with(solver: &str) -> (f64, Vec<f64>) {
+    let mut highs = quiet_highs();
+    highs.pass_model(&small_lp()).unwrap();
+    highs.set_string_option("solver", solver).unwrap();
+    highs.run().unwrap();
+    assert_eq!(highs.model_status(), MODEL_STATUS_OPTIMAL);
+    (
+        highs.objective_value(),
+        highs.get_solution().unwrap().col_value,
+    )
+}
+
+#[test]
+fn hipo_matches_simplex() {
+    let (simplex_objective, simplex_solution) = solve_with("simplex");
+    let (hipo_objective, hipo_solution) = solve_with("hipo");
+
+    assert!(
+        (hipo_objective - simplex_objective).abs() < 1e-6,
+        "objective: hipo {} vs simplex {}",
+        hipo_objective,
+        simplex_objective
+    );
+    for (hipo, simplex) in hipo_solution.iter().zip(&simplex_solution) {
+        assert!(
+            (hipo - simplex).abs() < 1e-6,
+            "solution: hipo {:?} vs simplex {:?}",
+            hipo_solution,
+            simplex_solution
+        );
+    }
+}
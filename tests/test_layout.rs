@@ -0,0 +1,91 @@
+//! Compares the layout of the generated callback data types with the one computed by the C
+//! compiler in `layout_probes.c`. Unlike bindgen's layout tests, these also run when the
+//! bindings are generated by parsing the headers for the host, as for emscripten. The probes
+//! are only linked with the `layout-probes` feature.
+#![cfg(all(highs_1_6, feature = "layout-probes"))]
+#![allow(non_upper_case_globals)]
+
+use std::mem::{align_of, offset_of, size_of};
+
+use highs_sys::*;
+
+extern "C" {
+    static highs_sys_layout_HighsCallbackDataOut_size: usize;
+    static highs_sys_layout_HighsCallbackDataOut_align: usize;
+    static highs_sys_layout_HighsCallbackDataOut_log_type: usize;
+    static highs_sys_layout_HighsCallbackDataOut_running_time: usize;
+    static highs_sys_layout_HighsCallbackDataOut_objective_function_value: usize;
+    static highs_sys_layout_HighsCallbackDataOut_mip_node_count: usize;
+    static highs_sys_layout_HighsCallbackDataOut_mip_primal_bound: usize;
+    static highs_sys_layout_HighsCallbackDataOut_mip_dual_bound: usize;
+    static highs_sys_layout_HighsCallbackDataOut_mip_gap: usize;
+    static highs_sys_layout_HighsCallbackDataIn_size: usize;
+    static highs_sys_layout_HighsCallbackDataIn_align: usize;
+    static highs_sys_layout_HighsCallbackDataIn_user_interrupt: usize;
+}
+
+#[test]
+fn callback_data_out_layout() {
+    unsafe {
+        assert_eq!(
+            size_of::<HighsCallbackDataOut>(),
+            highs_sys_layout_HighsCallbackDataOut_size
+        );
+        assert_eq!(
+            align_of::<HighsCallbackDataOut>(),
+            highs_sys_layout_HighsCallbackDataOut_align
+        );
+        assert_eq!(
+            offset_of!(HighsCallbackDataOut, log_type),
+            highs_sys_layout_HighsCallbackDataOut_log_type
+        );
+        assert_eq!(
+            offset_of!(HighsCallbackDataOut, running_time),
+            highs_sys_layout_HighsCallbackDataOut_running_time
+        );
+        assert_eq!(
+            offset_of!(HighsCallbackDataOut, objective_function_value),
+            highs_sys_layout_HighsCallbackDataOut_objective_function_value
+        );
+        assert_eq!(
+            offset_of!(HighsCallbackDataOut, mip_node_count),
+            highs_sys_layout_HighsCallbackDataOut_mip_node_count
+        );
+        assert_eq!(
+            offset_of!(HighsCallbackDataOut, mip_primal_bound),
+            highs_sys_layout_HighsCallbackDataOut_mip_primal_bound
+        );
+        assert_eq!(
+            offset_of!(HighsCallbackDataOut, mip_dual_bound),
+            highs_sys_layout_HighsCallbackDataOut_mip_dual_bound
+        );
+        assert_eq!(
+            offset_of!(HighsCallbackDataOut, mip_gap),
+            highs_sys_layout_HighsCallbackDataOut_mip_gap
+        );
+    }
+}
+
+#[test]
+fn callback_data_in_layout() {
+    unsafe {
+        assert_eq!(
+            size_of::<HighsCallbackDataIn>(),
+            highs_sys_layout_HighsCallbackDataIn_size
+        );
+        assert_eq!(
+            align_of::<HighsCallbackDataIn>(),
+            highs_sys_layout_HighsCallbackDataIn_align
+        );
+        assert_eq!(
+            offset_of!(HighsCallbackDataIn, user_interrupt),
+            highs_sys_layout_HighsCallbackDataIn_user_interrupt
+        );
+    }
+}
+
+#[test]
+fn callback_data_in_default_is_zeroed() {
+    let data_in = HighsCallbackDataIn::default();
+    assert_eq!(data_in.user_interrupt, 0);
+}
@@ -0,0 +1,27 @@
+//! A system-installed HiGHS may lack functions declared in its headers, which would only fail
+//! when linking downstream binaries. This test references every `Highs_*` function in the
+//! bindings, so such a library already fails to link here.
+
+use highs_sys::*;
+
+include!(concat!(env!("OUT_DIR"), "/highs_functions.rs"));
+
+#[test]
+fn every_function_is_linked() {
+    let functions = highs_functions();
+    for name in [
+        "Highs_create",
+        "Highs_run",
+        "Highs_lpCall",
+        "Highs_getSolution",
+    ] {
+        assert!(
+            functions.iter().any(|&(function, _)| function == name),
+            "{} is missing from the bindings",
+            name
+        );
+    }
+    for (name, address) in functions {
+        assert!(!std::hint::black_box(address).is_null(), "{}", name);
+    }
+}
@@ -0,0 +1,186 @@
+mod common;
+
+use common::{fixture, names, quiet_highs, read_fixture};
+use highs_sys::*;
+
+#[test]
+fn write_lp() {
+    let inf = f64::INFINITY;
+    let model = Model {
+        sense: OBJECTIVE_SENSE_MAXIMIZE,
+        offset: 1.5,
+        col_cost: vec![1., -2., 0.],
+        col_lower: vec![0., -inf, 2.],
+        col_upper: vec![1., 4., 10.],
+        row_lower: vec![1., 2., -inf],
+        row_upper: vec![3., 2., inf],
+        a_matrix: SparseMatrix {
+            start: vec![0, 1, 3],
+            index: vec![0, 0, 2, 1],
+            value: vec![1., 1., 1., -1.],
+        },
+        hessian: SparseMatrix {
+            start: vec![0, 2, 2],
+            index: vec![0, 1],
+            value: vec![2., -1.],
+        },
+        integrality: vec![
+            VAR_TYPE_INTEGER,
+            VAR_TYPE_CONTINUOUS,
+            VAR_TYPE_SEMI_CONTINUOUS,
+        ],
+        ..Model::default()
+    };
+    // The free row r2 is left out.
+    assert_eq!(
+        model.to_lp(),
+        "Maximize
+ obj: 1.0 c0 - 2.0 c1 + 0.0 c2 + 1.5 + [ 2.0 c0 ^ 2 - 2.0 c0 * c1 ] / 2
+Subject To
+ r0: 1.0 <= 1.0 c0 + 1.0 c1 <= 3.0
+ r1: -1.0 c2 = 2.0
+Bounds
+ -inf <= c1 <= 4.0
+ 2.0 <= c2 <= 10.0
+Binary
+ c0
+Semi-continuous
+ c2
+End
+"
+    );
+}
+
+#[test]
+fn unnamed_columns_and_rows_get_unique_names() {
+    let model = Model {
+        col_cost: vec![1., 1.],
+        col_lower: vec![0., 0.],
+        col_upper: vec![1., 1.],
+        row_lower: vec![1.],
+        row_upper: vec![2.],
+        a_matrix: SparseMatrix {
+            start: vec![0, 1],
+            index: vec![0, 0],
+            value: vec![1., 1.],
+        },
+        col_names: vec!["c1".to_string(), String::new()],
+        row_names: vec!["obj".to_string()],
+        ..Model::default()
+    };
+    assert_eq!(
+        model.to_lp(),
+        "Minimize
+ obj_: 1.0 c1 + 1.0 c1_
+Subject To
+ obj: 1.0 <= 1.0 c1 + 1.0 c1_ <= 2.0
+Bounds
+ 0.0 <= c1 <= 1.0
+ 0.0 <= c1_ <= 1.0
+End
+"
+    );
+}
+
+#[test]
+fn invalid_names_are_renamed() {
+    let model = Model {
+        col_cost: vec![1.; 5],
+        col_lower: vec![0.; 5],
+        col_upper: vec![1.; 5],
+        row_lower: vec![1.],
+        row_upper: vec![2.],
+        a_matrix: SparseMatrix {
+            start: vec![0, 1, 2, 3, 4],
+            index: vec![0; 5],
+            value: vec![1.; 5],
+        },
+        col_names: names(&["x 0", "x_0", "1st", "Free", "e2"]),
+        row_names: vec!["a+b-c: <=".to_string()],
+        ..Model::default()
+    };
+    // "x 0" comes first and keeps "x_0", so the column named "x_0" is renamed.
+    assert_eq!(
+        model.to_lp(),
+        "Minimize
+ obj: 1.0 x_0 + 1.0 x_0_ + 1.0 _1st + 1.0 _Free + 1.0 _e2
+Subject To
+ a_b_c____: 1.0 <= 1.0 x_0 + 1.0 x_0_ + 1.0 _1st + 1.0 _Free + 1.0 _e2 <= 2.0
+Bounds
+ 0.0 <= x_0 <= 1.0
+ 0.0 <= x_0_ <= 1.0
+ 0.0 <= _1st <= 1.0
+ 0.0 <= _Free <= 1.0
+ 0.0 <= _e2 <= 1.0
+End
+"
+    );
+}
+
+#[test]
+fn rows_without_columns_are_left_out() {
+    let model = Model {
+        offset: 2.,
+        row_lower: vec![0.],
+        row_upper: vec![1.],
+        ..Model::default()
+    };
+    assert_eq!(
+        model.to_lp(),
+        "Minimize\n obj: 2.0\nSubject To\nBounds\nEnd\n"
+    );
+}
+
+#[test]
+fn long_lines_are_wrapped() {
+    let model = Model {
+        col_cost: vec![1.; 100],
+        col_lower: vec![0.; 100],
+        col_upper: vec![1.; 100],
+        ..Model::default()
+    };
+    let lp = model.to_lp();
+    assert!(lp.lines().all(|line| line.len() < 100), "{}", lp);
+    assert!(lp.contains(" + 1.0 c99\n"));
+}
+
+#[test]
+fn highs_reads_written_lp() {
+    for name in &["highs_call.mps", "mip_features.mps", "qp.mps"] {
+        let mut highs = quiet_highs();
+        highs.read_model(fixture(name)).unwrap();
+        let model = highs.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap();
+
+        let mut reread = quiet_highs();
+        reread
+            .read_model_from_str(ModelFormat::Lp, &model.to_lp())
+            .unwrap();
+        assert_eq!(
+            reread.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap(),
+            model,
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn highs_reads_renamed_names() {
+    // The fixed MPS fixture has spaces in its names.
+    let model = Model::from_mps(&read_fixture("highs_call_fixed.mps")).unwrap();
+    let mut highs = quiet_highs();
+    highs
+        .read_model_from_str(ModelFormat::Lp, &model.to_lp())
+        .unwrap();
+    let reread = highs.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap();
+    assert_eq!(reread.col_names, ["X_0", "X_1"]);
+    assert_eq!(reread.row_names, ["ROW_0", "ROW_1", "ROW_2"]);
+    assert_eq!(
+        reread,
+        Model {
+            col_names: reread.col_names.clone(),
+            row_names: reread.row_names.clone(),
+            ..model
+        }
+    );
+}
@@ -0,0 +1,282 @@
+mod common;
+
+use std::ptr::null;
+
+use common::quiet_highs;
+use highs_sys::*;
+
+fn solution(highs: &Highs) -> Vec<f64> {
+    let num_col = unsafe { Highs_getNumCol(highs.as_ptr()) } as usize;
+    let num_row = unsafe { Highs_getNumRow(highs.as_ptr()) } as usize;
+    let mut colvalue = vec![0.; num_col];
+    let mut coldual = vec![0.; num_col];
+    let mut rowvalue = vec![0.; num_row];
+    let mut rowdual = vec![0.; num_row];
+    unsafe {
+        Highs_getSolution(
+            highs.as_ptr(),
+            colvalue.as_mut_ptr(),
+            coldual.as_mut_ptr(),
+            rowvalue.as_mut_ptr(),
+            rowdual.as_mut_ptr(),
+        );
+    }
+    colvalue
+}
+
+/// Builds the MIP
+/// Max    f  = 2x_0 + 3x_1
+/// s.t.                x_1 <= 6
+///       10 <=  x_0 + 2x_1 <= 14
+///        8 <= 2x_0 +  x_1
+/// 0 <= x_0 <= 3; 1 <= x_1; x_1 integer
+/// through incremental edits, whose optimum is x = (2, 6).
+fn incremental_mip() -> Highs {
+    let mut highs = quiet_highs();
+    let ptr = highs.as_mut_ptr();
+    unsafe {
+        let inf = Highs_getInfinity(ptr);
+        let colcost = [2.0, 3.0];
+        let collower = [0.0, 1.0];
+        let colupper = [3.0, inf];
+        let status = Highs_addCols(
+            ptr,
+            2,
+            colcost.as_ptr(),
+            collower.as_ptr(),
+            colupper.as_ptr(),
+            0,
+            null(),
+            null(),
+            null(),
+        );
+        assert_eq!(status, STATUS_OK, "addCols");
+
+        let rowlower = [-inf, 10.0, 8.0];
+        let rowupper = [6.0, 14.0, inf];
+        let arstart: [HighsInt; 3] = [0, 1, 3];
+        let arindex: [HighsInt; 5] = [1, 0, 1, 0, 1];
+        let arvalue = [1.0, 1.0, 2.0, 2.0, 1.0];
+        let status = Highs_addRows(
+            ptr,
+            3,
+            rowlower.as_ptr(),
+            rowupper.as_ptr(),
+            5,
+            arstart.as_ptr(),
+            arindex.as_ptr(),
+            arvalue.as_ptr(),
+        );
+        assert_eq!(status, STATUS_OK, "addRows");
+
+        assert_eq!(
+            Highs_changeObjectiveSense(ptr, OBJECTIVE_SENSE_MAXIMIZE),
+            STATUS_OK
+        );
+        assert_eq!(
+            Highs_changeColIntegrality(ptr, 1, VAR_TYPE_INTEGER),
+            STATUS_OK
+        );
+    }
+    highs
+}
+
+#[test]
+fn get_model_after_incremental_edits() {
+    let highs = incremental_mip();
+    let inf = unsafe { Highs_getInfinity(highs.as_ptr()) };
+
+    let model = highs.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap();
+    assert_eq!(model.sense, OBJECTIVE_SENSE_MAXIMIZE);
+    assert_eq!(model.offset, 0.);
+    assert_eq!(model.col_cost, [2.0, 3.0]);
+    assert_eq!(model.col_lower, [0.0, 1.0]);
+    assert_eq!(model.col_upper, [3.0, inf]);
+    assert_eq!(model.row_lower, [-inf, 10.0, 8.0]);
+    assert_eq!(model.row_upper, [6.0, 14.0, inf]);
+    assert_eq!(model.a_format, MATRIX_FORMAT_COLUMN_WISE);
+    assert_eq!(
+        model.a_matrix,
+        SparseMatrix {
+            start: vec![0, 2],
+            index: vec![1, 2, 0, 1, 2],
+            value: vec![1.0, 2.0, 1.0, 2.0, 1.0],
+        }
+    );
+    assert_eq!(model.hessian, SparseMatrix::default());
+    assert_eq!(model.integrality, [VAR_TYPE_CONTINUOUS, VAR_TYPE_INTEGER]);
+
+    let row_wise = highs.get_model(MATRIX_FORMAT_ROW_WISE).unwrap();
+    assert_eq!(
+        row_wise.a_matrix,
+        SparseMatrix {
+            start: vec![0, 1, 3],
+            index: vec![1, 0, 1, 0, 1],
+            value: vec![1.0, 1.0, 2.0, 2.0, 1.0],
+        }
+    );
+}
+
+#[test]
+fn model_round_trip_gives_same_solution() {
+    let mut original = incremental_mip();
+    let model = original.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap();
+
+    let mut copy = quiet_highs();
+    copy.pass_model(&model).unwrap();
+    assert_eq!(copy.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap(), model);
+
+    original.run().unwrap();
+    copy.run().unwrap();
+    assert_eq!(original.model_status(), MODEL_STATUS_OPTIMAL);
+    assert_eq!(copy.model_status(), MODEL_STATUS_OPTIMAL);
+    assert_eq!(copy.objective_value(), original.objective_value());
+    assert_eq!(solution(&copy), solution(&original));
+    assert_eq!(solution(&copy), [2.0, 6.0]);
+}
+
+#[test]
+fn quadratic_model_round_trip() {
+    // Min    f  = x_0^2 + x_1^2 - x_0
+    // s.t.   1 <= x_0 + x_1
+    // whose optimum is x = (0.75, 0.25).
+    let mut original = quiet_highs();
+    let inf = unsafe { Highs_getInfinity(original.as_ptr()) };
+    original
+        .pass_model(&Model {
+            col_cost: vec![-1.0, 0.0],
+            col_lower: vec![-inf, -inf],
+            col_upper: vec![inf, inf],
+            row_lower: vec![1.0],
+            row_upper: vec![inf],
+            a_matrix: SparseMatrix {
+                start: vec![0, 1],
+                index: vec![0, 0],
+                value: vec![1.0, 1.0],
+            },
+            hessian: SparseMatrix {
+                start: vec![0, 1],
+                index: vec![0, 1],
+                value: vec![2.0, 2.0],
+            },
+            ..Model::default()
+        })
+        .unwrap();
+
+    let model = original.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap();
+    assert_eq!(model.hessian.value, [2.0, 2.0]);
+    let mut copy = quiet_highs();
+    copy.pass_model(&model).unwrap();
+
+    original.run().unwrap();
+    copy.run().unwrap();
+    assert_eq!(copy.model_status(), MODEL_STATUS_OPTIMAL);
+    assert_eq!(copy.objective_value(), original.objective_value());
+    let x = solution(&copy);
+    assert!(
+        (x[0] - 0.75).abs() < 1e-6 && (x[1] - 0.25).abs() < 1e-6,
+        "x = {:?}",
+        x
+    );
+}
+
+#[test]
+fn invalid_models_are_rejected() {
+    let valid = Model {
+        col_cost: vec![1.0, 1.0],
+        col_lower: vec![0.0, 0.0],
+        col_upper: vec![1.0, 1.0],
+        row_lower: vec![1.0],
+        row_upper: vec![2.0],
+        a_matrix: SparseMatrix {
+            start: vec![0, 1],
+            index: vec![0, 0],
+            value: vec![1.0, 1.0],
+        },
+        ..Model::default()
+    };
+    assert_eq!(valid.validate(), Ok(()));
+    let invalid = |model: Model| match model.validate() {
+        Err(HighsError::InvalidModel(reason)) => reason,
+        result => panic!("{:?}", result),
+    };
+    assert_eq!(
+        invalid(Model {
+            col_upper: vec![1.0],
+            ..valid.clone()
+        }),
+        "col_upper has 1 entries instead of 2"
+    );
+    assert_eq!(
+        invalid(Model {
+            row_upper: Vec::new(),
+            ..valid.clone()
+        }),
+        "row_upper has 0 entries instead of 1"
+    );
+    let with_matrix = |start: Vec<HighsInt>, index: Vec<HighsInt>| Model {
+        a_matrix: SparseMatrix {
+            start,
+            index,
+            value: vec![1.0, 1.0],
+        },
+        ..valid.clone()
+    };
+    assert_eq!(
+        invalid(with_matrix(vec![0], vec![0, 0])),
+        "a_matrix has 1 starts for 2 vectors"
+    );
+    assert_eq!(
+        invalid(with_matrix(vec![0, 1], vec![0])),
+        "a_matrix has 1 indices for 2 values"
+    );
+    assert_eq!(
+        invalid(with_matrix(vec![0, 3], vec![0, 0])),
+        "a_matrix has an invalid start 3"
+    );
+    assert_eq!(
+        invalid(with_matrix(vec![0, 1], vec![0, 1])),
+        "a_matrix has an invalid index 1"
+    );
+    assert_eq!(
+        invalid(Model {
+            a_format: MATRIX_FORMAT_ROW_WISE,
+            ..valid.clone()
+        }),
+        "a_matrix has 2 starts for 1 vectors"
+    );
+    assert_eq!(
+        invalid(Model {
+            hessian: SparseMatrix {
+                start: vec![0],
+                index: vec![0],
+                value: vec![2.0],
+            },
+            ..valid.clone()
+        }),
+        "hessian has 1 starts for 2 vectors"
+    );
+}
+
+#[test]
+fn pass_model_rejects_invalid_models() {
+    let short = Model {
+        col_cost: vec![1.0, 1.0],
+        col_lower: Vec::new(),
+        col_upper: vec![1.0, 1.0],
+        ..Model::default()
+    };
+    let error = quiet_highs().pass_model(&short).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid model: col_lower has 0 entries instead of 2"
+    );
+}
+
+#[test]
+fn get_model_rejects_invalid_formats() {
+    assert_eq!(
+        incremental_mip().get_model(7),
+        Err(HighsError::InvalidMatrixFormat(7))
+    );
+}
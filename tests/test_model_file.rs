@@ -0,0 +1,168 @@
+mod common;
+
+use std::error::Error;
+use std::path::Path;
+
+use common::{fixture, quiet_highs, temp_file};
+use highs_sys::*;
+
+/// Solves the model in `highs`, which must be the LP from `test_highs_call.rs`.
+fn assert_solves_highs_call_lp(mut highs: Highs) {
+    highs.run().unwrap();
+    assert_eq!(highs.model_status(), MODEL_STATUS_OPTIMAL);
+    assert_eq!(highs.objective_value(), 16.);
+    assert_eq!(highs.get_solution().unwrap().col_value, [2., 4.]);
+}
+
+#[test]
+fn format_detection() {
+    let format = |path: &str| ModelFormat::from_path(Path::new(path));
+    assert_eq!(format("model.mps"), Some(ModelFormat::Mps));
+    assert_eq!(format("dir.d/model.MPS"), Some(ModelFormat::Mps));
+    assert_eq!(format("model.mps.gz"), Some(ModelFormat::Mps));
+    assert_eq!(format("model.lp"), Some(ModelFormat::Lp));
+    assert_eq!(format("model.lp.gz"), Some(ModelFormat::Lp));
+    assert_eq!(format("model.gz"), None);
+    assert_eq!(format("model.txt"), None);
+    assert_eq!(format("model"), None);
+}
+
+#[test]
+fn read_mps_and_lp() {
+    let mut highs = quiet_highs();
+    highs.read_model(fixture("highs_call.mps")).unwrap();
+    assert_solves_highs_call_lp(highs);
+
+    let mut highs = quiet_highs();
+    highs.read_model(fixture("highs_call.lp")).unwrap();
+    assert_solves_highs_call_lp(highs);
+}
+
+#[cfg(feature = "libz")]
+#[test]
+fn read_gzipped_mps() {
+    let mut highs = quiet_highs();
+    highs.read_model(fixture("highs_call.mps.gz")).unwrap();
+    assert_solves_highs_call_lp(highs);
+}
+
+#[test]
+fn mps_round_trip() {
+    let mut original = quiet_highs();
+    original.read_model(fixture("highs_call.mps")).unwrap();
+    let path = temp_file("mps_round_trip.mps");
+    original.write_model(&path).unwrap();
+
+    let mut copy = quiet_highs();
+    copy.read_model(&path).unwrap();
+    assert_eq!(
+        copy.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap(),
+        original.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap()
+    );
+    assert_solves_highs_call_lp(copy);
+}
+
+#[test]
+fn lp_round_trip() {
+    let mut original = quiet_highs();
+    original.read_model(fixture("highs_call.mps")).unwrap();
+    let path = temp_file("lp_round_trip.lp");
+    original.write_model(&path).unwrap();
+
+    let mut copy = quiet_highs();
+    copy.read_model(&path).unwrap();
+    assert_solves_highs_call_lp(copy);
+}
+
+#[test]
+fn read_errors() {
+    let mut highs = quiet_highs();
+    assert!(matches!(
+        highs.read_model(fixture("not_a_model.mps")),
+        Err(ModelFileError::Parse {
+            format: ModelFormat::Mps,
+            ..
+        })
+    ));
+    assert!(matches!(
+        highs.read_model(fixture("missing.mps")),
+        Err(ModelFileError::Path(PathError::Io { source, .. })) if source.kind() == std::io::ErrorKind::NotFound
+    ));
+    assert!(matches!(
+        highs.read_model(fixture("highs_call.txt")),
+        Err(ModelFileError::UnknownFormat(_))
+    ));
+    #[cfg(not(feature = "libz"))]
+    assert!(matches!(
+        highs.read_model(fixture("highs_call.mps.gz")),
+        Err(ModelFileError::CompressionUnsupported(_))
+    ));
+}
+
+#[test]
+fn write_errors() {
+    let mut highs = quiet_highs();
+    highs.read_model(fixture("highs_call.mps")).unwrap();
+    assert!(matches!(
+        highs.write_model(temp_file("model.mps.gz")),
+        Err(ModelFileError::CompressionUnsupported(_))
+    ));
+    assert!(matches!(
+        highs.write_model(temp_file("model.json")),
+        Err(ModelFileError::UnknownFormat(_))
+    ));
+    assert!(matches!(
+        highs.write_model(temp_file("no/such/dir/model.mps")),
+        Err(ModelFileError::Write { .. })
+    ));
+}
+
+#[test]
+fn read_model_from_str() {
+    let mut highs = quiet_highs();
+    highs
+        .read_model_from_str(ModelFormat::Mps, include_str!("fixtures/highs_call.mps"))
+        .unwrap();
+    assert_solves_highs_call_lp(highs);
+
+    let mut highs = quiet_highs();
+    highs
+        .read_model_from_str(ModelFormat::Lp, include_str!("fixtures/highs_call.lp"))
+        .unwrap();
+    assert_solves_highs_call_lp(highs);
+
+    let mut highs = quiet_highs();
+    assert!(matches!(
+        highs.read_model_from_str(ModelFormat::Mps, "This is not a model.\n"),
+        Err(ModelFileError::Parse { .. })
+    ));
+}
+
+#[test]
+fn write_model_to_string() {
+    let mut original = quiet_highs();
+    original.read_model(fixture("highs_call.mps")).unwrap();
+    for format in [ModelFormat::Mps, ModelFormat::Lp].iter().copied() {
+        let content = original.write_model_to_string(format).unwrap();
+        let mut copy = quiet_highs();
+        copy.read_model_from_str(format, &content).unwrap();
+        assert_solves_highs_call_lp(copy);
+    }
+}
+
+#[test]
+fn path_errors_are_the_source() {
+    let path_error = || PathError::Invalid("invalid\0path".into());
+    let errors: [Box<dyn Error>; 3] = [
+        Box::new(ModelFileError::from(path_error())),
+        Box::new(SolutionFileError::from(path_error())),
+        Box::new(OptionsFileError::from(path_error())),
+    ];
+    for error in &errors {
+        assert_eq!(error.to_string(), path_error().to_string());
+        assert_eq!(
+            error.source().map(ToString::to_string),
+            Some(path_error().to_string())
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! In-memory model reading and writing from many threads at once. This is the only test in this
+//! file, so that no other test has temporary files while it checks that they are all removed.
+
+mod common;
+
+use common::quiet_highs;
+use highs_sys::*;
+
+fn temp_dirs_of_this_process() -> Vec<String> {
+    let prefix = format!("highs-sys-{}-", std::process::id());
+    std::fs::read_dir(std::env::temp_dir())
+        .unwrap()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.starts_with(&prefix))
+        .collect()
+}
+
+fn round_trip(format: ModelFormat) -> f64 {
+    let mut original = quiet_highs();
+    original
+        .read_model_from_str(ModelFormat::Mps, include_str!("fixtures/highs_call.mps"))
+        .unwrap();
+    let content = original.write_model_to_string(format).unwrap();
+
+    let mut copy = quiet_highs();
+    copy.read_model_from_str(format, &content).unwrap();
+    copy.run().unwrap();
+    assert_eq!(copy.model_status(), MODEL_STATUS_OPTIMAL);
+    copy.objective_value()
+}
+
+#[cfg(not(target_os = "windows"))] // like `highs_functions_multithread`
+#[test]
+fn concurrent_in_memory_round_trips() {
+    let threads: Vec<_> = (0..128)
+        .map(|i| {
+            let format = if i % 2 == 0 {
+                ModelFormat::Mps
+            } else {
+                ModelFormat::Lp
+            };
+            std::thread::spawn(move || round_trip(format))
+        })
+        .collect();
+    for t in threads {
+        assert_eq!(t.join().expect("Thread should not panic"), 16.);
+    }
+    assert_eq!(temp_dirs_of_this_process(), Vec::<String>::new());
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::quiet_highs;
+use highs_sys::*;
+
+/// Builds the MIP
+/// Max    f  = 2x_0 + 3x_1
+/// s.t.                x_1 <= 6
+///       10 <=  x_0 + 2x_1 <= 14
+///        8 <= 2x_0 +  x_1
+/// 0 <= x_0 <= 3; 1 <= x_1; x_1 integer
+/// whose optimum is x = (2, 6).
+fn mip() -> (Model, Var, Var) {
+    let mut model = Model {
+        sense: OBJECTIVE_SENSE_MAXIMIZE,
+        ..Model::default()
+    };
+    let x0 = model.add_var(0.0..=3.0, 2.0);
+    let x1 = model.add_integer_var(1.0.., 3.0);
+    model.add_constr(x1, ..=6.0);
+    model.add_constr(x0 + 2.0 * x1, 10.0..=14.0);
+    model.add_constr(2.0 * x0 + x1, 8.0..);
+    (model, x0, x1)
+}
+
+#[test]
+fn build_model() {
+    let inf = f64::INFINITY;
+    let (model, x0, x1) = mip();
+    assert_eq!((x0.index(), x1.index()), (0, 1));
+    assert_eq!(
+        model,
+        Model {
+            sense: OBJECTIVE_SENSE_MAXIMIZE,
+            col_cost: vec![2., 3.],
+            col_lower: vec![0., 1.],
+            col_upper: vec![3., inf],
+            row_lower: vec![-inf, 10., 8.],
+            row_upper: vec![6., 14., inf],
+            a_format: MATRIX_FORMAT_ROW_WISE,
+            a_matrix: SparseMatrix {
+                start: vec![0, 1, 3],
+                index: vec![1, 0, 1, 0, 1],
+                value: vec![1., 1., 2., 2., 1.],
+            },
+            integrality: vec![VAR_TYPE_CONTINUOUS, VAR_TYPE_INTEGER],
+            ..Model::default()
+        }
+    );
+}
+
+#[test]
+fn expressions() {
+    let mut model = Model::default();
+    let vars: Vec<Var> = (0..3).map(|_| model.add_var(.., 0.)).collect();
+    let (x, y, z) = (vars[0], vars[1], vars[2]);
+
+    let expr = 2.0 * x - (y - 1.0) * 3.0 + 4.0 - z;
+    assert_eq!(expr.terms(), [(x, 2.), (y, -3.), (z, -1.)]);
+    assert_eq!(expr.constant(), 7.);
+    assert_eq!(expr.value(&[1., 2., 3.]), 0.);
+    assert_eq!((-expr).constant(), -7.);
+
+    let sum: LinExpr = vars.iter().map(|&v| 2.0 * v).sum();
+    assert_eq!(sum.terms(), [(x, 2.), (y, 2.), (z, 2.)]);
+    let mut total: LinExpr = vars.iter().sum();
+    total -= x;
+    total += 1.0;
+    assert_eq!(total.value(&[5., 1., 1.]), 3.);
+    assert_eq!(vars.iter().sum::<LinExpr>(), vars.into_iter().sum());
+
+    // Terms of the same variable are merged, and the constant is moved to the bounds.
+    let constr = model.add_constr(x + y - x + 2.0 * (y + 1.0) - z + x, 1.0..=4.0);
+    assert_eq!(constr.index(), 0);
+    assert_eq!(model.a_matrix.index, [0, 1, 2]);
+    assert_eq!(model.a_matrix.value, [1., 3., -1.]);
+    assert_eq!((model.row_lower[0], model.row_upper[0]), (-1., 2.));
+}
+
+#[test]
+fn add_to_existing_model() {
+    let inf = f64::INFINITY;
+    let mut model = Model {
+        col_cost: vec![1., 1.],
+        col_lower: vec![0., 0.],
+        col_upper: vec![inf, inf],
+        row_lower: vec![1.],
+        row_upper: vec![inf],
+        a_matrix: SparseMatrix {
+            start: vec![0, 1],
+            index: vec![0, 0],
+            value: vec![1., 1.],
+        },
+        col_names: vec!["a".to_string(), "b".to_string()],
+        ..Model::default()
+    };
+    let c = model.add_var(0.0..=1.0, 5.);
+    model.add_constr(c - 1.0, 0.0..=0.0);
+    assert_eq!(model.a_format, MATRIX_FORMAT_ROW_WISE);
+    assert_eq!(model.a_matrix.start, [0, 2]);
+    assert_eq!(model.a_matrix.index, [0, 1, 2]);
+    assert_eq!(model.row_lower, [1., 1.]);
+    assert_eq!(model.col_names, ["a", "b", ""]);
+}
+
+#[test]
+#[should_panic(expected = "variable 0 is not in this model")]
+fn variables_of_other_models_panic() {
+    let x = Model::default().add_var(.., 0.);
+    Model::default().add_constr(x, ..=1.0);
+}
+
+#[test]
+fn solve_built_model() {
+    let (model, x0, x1) = mip();
+    let mut highs = quiet_highs();
+    highs.pass_model(&model).unwrap();
+    highs.run().unwrap();
+    assert_eq!(highs.model_status(), MODEL_STATUS_OPTIMAL);
+    let solution = highs.get_solution().unwrap();
+    assert!((solution.col_value[x0.index()] - 2.).abs() < 1e-6);
+    assert!((solution.col_value[x1.index()] - 6.).abs() < 1e-6);
+    assert!((highs.objective_value() - 22.).abs() < 1e-6);
+}
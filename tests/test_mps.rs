@@ -0,0 +1,224 @@
+mod common;
+
+use common::{fixture, names, quiet_highs, read_fixture};
+use highs_sys::*;
+
+fn mip_features() -> Model {
+    let inf = f64::INFINITY;
+    Model {
+        sense: OBJECTIVE_SENSE_MAXIMIZE,
+        offset: 10.,
+        col_cost: vec![1., 2., -1., 0.5, 3.],
+        col_lower: vec![0., -inf, 0., 1., 0.],
+        col_upper: vec![4., 8., inf, 6., 1.],
+        row_lower: vec![0., 15., 2., 1.],
+        row_upper: vec![0., 20., 6., 3.],
+        a_format: MATRIX_FORMAT_COLUMN_WISE,
+        a_matrix: SparseMatrix {
+            start: vec![0, 2, 4, 6, 7],
+            index: vec![1, 0, 0, 2, 1, 3, 2, 1],
+            value: vec![1., 1., -1., 1., 2., 1., 1., 1.],
+        },
+        hessian: SparseMatrix::default(),
+        integrality: vec![
+            VAR_TYPE_INTEGER,
+            VAR_TYPE_CONTINUOUS,
+            VAR_TYPE_CONTINUOUS,
+            VAR_TYPE_SEMI_CONTINUOUS,
+            VAR_TYPE_INTEGER,
+        ],
+        col_names: names(&["x", "y", "z", "s", "b"]),
+        row_names: names(&["balance", "cap", "demand", "link"]),
+    }
+}
+
+#[test]
+fn read_free_mps() {
+    let model = Model::from_mps_with_format(&read_fixture("mip_features.mps"), MpsFormat::Free);
+    assert_eq!(model.unwrap(), mip_features());
+}
+
+#[test]
+fn read_fixed_mps_with_spaces_in_names() {
+    let fixed = read_fixture("highs_call_fixed.mps");
+    assert_eq!(
+        Model::from_mps_with_format(&fixed, MpsFormat::Free)
+            .unwrap_err()
+            .kind,
+        MpsErrorKind::WrongFieldCount
+    );
+    let expected = Model {
+        col_names: names(&["X 0", "X 1"]),
+        row_names: names(&["ROW 0", "ROW 1", "ROW 2"]),
+        ..Model::from_mps(&read_fixture("highs_call.mps")).unwrap()
+    };
+    assert_eq!(Model::from_mps(&fixed).unwrap(), expected);
+    assert_eq!(
+        Model::from_mps_with_format(&fixed, MpsFormat::Fixed).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn write_and_read_back() {
+    let model = mip_features();
+    assert_eq!(Model::from_mps(&model.to_mps()).unwrap(), model);
+
+    let qp = Model::from_mps(&read_fixture("qp.mps")).unwrap();
+    assert_eq!(
+        qp.hessian,
+        SparseMatrix {
+            start: vec![0, 2],
+            index: vec![0, 1, 1],
+            value: vec![2., -1., 2.],
+        }
+    );
+    assert_eq!(Model::from_mps(&qp.to_mps()).unwrap(), qp);
+
+    // A row-wise matrix, a free row, a fixed column and a semi-integer column.
+    let inf = f64::INFINITY;
+    let row_wise = Model {
+        col_cost: vec![1., 0., 0.],
+        col_lower: vec![2., -3., 0.],
+        col_upper: vec![2., -1., 5.],
+        row_lower: vec![-inf, 1.],
+        row_upper: vec![inf, 1.],
+        a_format: MATRIX_FORMAT_ROW_WISE,
+        a_matrix: SparseMatrix {
+            start: vec![0, 2],
+            index: vec![0, 2, 1],
+            value: vec![1., 3., -2.],
+        },
+        integrality: vec![
+            VAR_TYPE_CONTINUOUS,
+            VAR_TYPE_CONTINUOUS,
+            VAR_TYPE_SEMI_INTEGER,
+        ],
+        ..Model::default()
+    };
+    let column_wise = Model {
+        a_format: MATRIX_FORMAT_COLUMN_WISE,
+        a_matrix: SparseMatrix {
+            start: vec![0, 1, 2],
+            index: vec![0, 1, 0],
+            value: vec![1., -2., 3.],
+        },
+        // The writer names unnamed columns and rows after their index.
+        col_names: names(&["c0", "c1", "c2"]),
+        row_names: names(&["r0", "r1"]),
+        ..row_wise.clone()
+    };
+    assert_eq!(Model::from_mps(&row_wise.to_mps()).unwrap(), column_wise);
+
+    // The objective is renamed if a row has its name.
+    let named = Model {
+        row_names: names(&["obj", ""]),
+        ..column_wise.clone()
+    };
+    let mps = named.to_mps();
+    assert!(mps.contains(" N  obj_\n"), "{}", mps);
+    let expected = Model {
+        row_names: names(&["obj", "r1"]),
+        ..column_wise
+    };
+    assert_eq!(Model::from_mps(&mps).unwrap(), expected);
+
+    // Unnamed columns and rows don't take the names of others.
+    let colliding = Model {
+        col_names: names(&["c1", "", "c2"]),
+        row_names: names(&["", "r0"]),
+        ..expected
+    };
+    let read = Model::from_mps(&colliding.to_mps()).unwrap();
+    assert_eq!(read.col_names, names(&["c1", "c1_", "c2"]));
+    assert_eq!(read.row_names, names(&["r0_", "r0"]));
+    assert_eq!(
+        read,
+        Model {
+            col_names: read.col_names.clone(),
+            row_names: read.row_names.clone(),
+            ..colliding
+        }
+    );
+}
+
+#[test]
+fn names_with_spaces_are_renamed() {
+    let fixed = Model::from_mps(&read_fixture("highs_call_fixed.mps")).unwrap();
+    let model = Model {
+        col_names: names(&["X 0", ""]),
+        row_names: names(&["ROW 0", "ROW_0", "ROW\t2"]),
+        ..fixed
+    };
+    let read = Model::from_mps(&model.to_mps()).unwrap();
+    assert_eq!(read.col_names, names(&["X_0", "c1"]));
+    assert_eq!(read.row_names, names(&["ROW_0", "ROW_0_", "ROW_2"]));
+    assert_eq!(
+        read,
+        Model {
+            col_names: read.col_names.clone(),
+            row_names: read.row_names.clone(),
+            ..model
+        }
+    );
+}
+
+#[test]
+fn errors_have_line_numbers() {
+    let error = |mps: &str| Model::from_mps(mps).unwrap_err();
+    assert_eq!(
+        error("ROWS\n N obj\n L c1\nCOLUMNS\n x c2 1\nENDATA\n"),
+        MpsError {
+            line: 5,
+            kind: MpsErrorKind::UnknownRow("c2".to_string()),
+        }
+    );
+    assert_eq!(
+        error("ROWS\n N obj\nCOLUMNS\n x obj one\nENDATA\n").to_string(),
+        "line 4: \"one\" is not a number"
+    );
+    assert_eq!(
+        error("ROWS\n N obj\nCOLUMNS\n x obj 1\nBOUNDS\n XX bnd x 1\nENDATA\n"),
+        MpsError {
+            line: 6,
+            kind: MpsErrorKind::InvalidBoundType("XX".to_string()),
+        }
+    );
+    assert_eq!(
+        error("ROWS\n L c1\nENDATA\n"),
+        MpsError {
+            line: 3,
+            kind: MpsErrorKind::NoObjectiveRow,
+        }
+    );
+    assert_eq!(
+        error("ROWS\n N obj\nCOLUMNS\n x obj 1\n").kind,
+        MpsErrorKind::MissingEndata
+    );
+}
+
+#[test]
+fn same_model_as_highs_reader() {
+    for name in &["highs_call.mps", "mip_features.mps", "qp.mps"] {
+        let mut highs = quiet_highs();
+        highs.read_model(fixture(name)).unwrap();
+        let read = highs.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap();
+        assert_eq!(
+            Model::from_mps(&read_fixture(name)).unwrap(),
+            read,
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn solve_parsed_qp() {
+    let mut highs = quiet_highs();
+    highs
+        .pass_model(&Model::from_mps(&read_fixture("qp.mps")).unwrap())
+        .unwrap();
+    highs.run().unwrap();
+    assert_eq!(highs.model_status(), MODEL_STATUS_OPTIMAL);
+    assert!((highs.objective_value() + 2.75).abs() < 1e-6);
+}
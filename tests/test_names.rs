@@ -0,0 +1,132 @@
+mod common;
+
+use common::{fixture, names, quiet_highs};
+use highs_sys::*;
+
+/// An LP with three columns, the last of which is in no row, and two rows:
+/// Min    f  = x_0 + 2x_1
+/// s.t.                x_1 <= 6
+///       10 <=  x_0 + 2x_1 <= 14
+/// 0 <= x_0 <= 3; 1 <= x_1; 0 <= x_2 <= 1
+fn model() -> Model {
+    let inf = f64::INFINITY;
+    Model {
+        col_cost: vec![1., 2., 0.],
+        col_lower: vec![0., 1., 0.],
+        col_upper: vec![3., inf, 1.],
+        row_lower: vec![-inf, 10.],
+        row_upper: vec![6., 14.],
+        a_matrix: SparseMatrix {
+            start: vec![0, 1, 3],
+            index: vec![1, 0, 1],
+            value: vec![1., 1., 2.],
+        },
+        ..Model::default()
+    }
+}
+
+fn highs_with(model: &Model) -> Highs {
+    let mut highs = quiet_highs();
+    highs.pass_model(model).unwrap();
+    highs
+}
+
+#[test]
+fn set_and_get_names() {
+    let mut highs = highs_with(&model());
+    assert_eq!(highs.get_col_names().unwrap(), Vec::<String>::new());
+    assert_eq!(highs.get_col_by_name("x").unwrap(), None);
+
+    highs.set_col_name(1, "x").unwrap();
+    highs.set_row_name(0, "limit").unwrap();
+    assert_eq!(highs.get_col_name(1).unwrap(), "x");
+    assert_eq!(highs.get_row_name(0).unwrap(), "limit");
+    assert_eq!(highs.get_col_by_name("x").unwrap(), Some(1));
+    assert_eq!(highs.get_row_by_name("limit").unwrap(), Some(0));
+    assert_eq!(highs.get_row_by_name("x").unwrap(), None);
+
+    // Renaming a column to its own name is not a duplicate.
+    highs.set_col_name(1, "x").unwrap();
+    highs.set_col_names(&["a", "b", "c"]).unwrap();
+    assert_eq!(highs.get_col_names().unwrap(), names(&["a", "b", "c"]));
+    assert_eq!(highs.get_col_by_name("x").unwrap(), None);
+}
+
+#[test]
+fn duplicate_names_are_rejected() {
+    let mut highs = highs_with(&model());
+    highs.set_col_name(0, "x").unwrap();
+    assert_eq!(
+        highs.set_col_name(2, "x"),
+        Err(HighsError::DuplicateName("x".to_string()))
+    );
+    // Columns and rows have separate names.
+    highs.set_row_name(0, "x").unwrap();
+
+    let error = highs.set_row_names(&["y", "y"]).unwrap_err();
+    assert_eq!(error, HighsError::DuplicateName("y".to_string()));
+    assert_eq!(error.to_string(), "the name \"y\" is already used");
+
+    let duplicate = Model {
+        col_names: names(&["a", "", "a"]),
+        ..model()
+    };
+    assert_eq!(
+        quiet_highs().pass_model(&duplicate),
+        Err(HighsError::DuplicateName("a".to_string()))
+    );
+    // Names are also unique among those that columns keep.
+    assert_eq!(
+        highs.set_col_names(&["", "x"]),
+        Err(HighsError::DuplicateName("x".to_string()))
+    );
+    highs.set_col_names(&["y", "x"]).unwrap();
+    assert_eq!(highs.get_col_names().unwrap(), names(&["y", "x", ""]));
+    assert_eq!(
+        highs.set_col_name(1, "nul\0"),
+        Err(HighsError::NulByte("nul\0".to_string()))
+    );
+}
+
+#[test]
+fn models_carry_names() {
+    let named = Model {
+        col_names: names(&["x", "y", "z"]),
+        row_names: names(&["limit", "range"]),
+        ..model()
+    };
+    let highs = highs_with(&named);
+    assert_eq!(highs.get_col_by_name("z").unwrap(), Some(2));
+    assert_eq!(highs.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap(), named);
+
+    let clone = highs.try_clone().unwrap();
+    assert_eq!(clone.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap(), named);
+
+    // Unnamed columns and rows have empty names.
+    let partly_named = Model {
+        col_names: names(&["", "y", ""]),
+        ..model()
+    };
+    let highs = highs_with(&partly_named);
+    assert_eq!(
+        highs.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap(),
+        partly_named
+    );
+}
+
+#[test]
+fn model_files_carry_names() {
+    let mut highs = quiet_highs();
+    highs.read_model(fixture("highs_call.mps")).unwrap();
+    assert_eq!(highs.get_col_by_name("X1").unwrap(), Some(1));
+    assert_eq!(highs.get_row_names().unwrap(), names(&["R0", "R1", "R2"]));
+
+    let model = highs.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap();
+    assert_eq!(Model::from_mps(&model.to_mps()).unwrap(), model);
+    let lp = model.to_lp();
+    assert!(
+        lp.contains(" R1: 10.0 <= 1.0 X0 + 2.0 X1 <= 14.0\n"),
+        "{}",
+        lp
+    );
+}
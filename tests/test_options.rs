@@ -0,0 +1,112 @@
+mod common;
+
+use std::fs;
+
+use common::{quiet_highs, temp_file};
+use highs_sys::*;
+
+#[test]
+fn options_from_vars() {
+    let vars = [
+        ("HIGHS_OPT_TIME_LIMIT", "30"),
+        ("HIGHS_OPT_presolve", "off"),
+        ("HIGHS_OPT_MIP_DETECT_SYMMETRY", "false"),
+        ("HIGHS_OPT_SIMPLEX_ITERATION_LIMIT", " 1000 "),
+        ("OTHER_TIME_LIMIT", "10"),
+        ("HIGHS_OPT_", "1"),
+    ];
+    let mut highs = quiet_highs();
+    assert_eq!(
+        highs
+            .options_from_vars("HIGHS_OPT_", vars.iter().copied())
+            .unwrap(),
+        [
+            "mip_detect_symmetry",
+            "simplex_iteration_limit",
+            "time_limit",
+            "presolve"
+        ]
+    );
+    assert_eq!(highs.get_option("time_limit"), Ok(OptionValue::Double(30.)));
+    assert_eq!(
+        highs.get_option("presolve"),
+        Ok(OptionValue::String("off".to_string()))
+    );
+    assert_eq!(
+        highs.get_option("mip_detect_symmetry"),
+        Ok(OptionValue::Bool(false))
+    );
+    assert_eq!(
+        highs.get_option("simplex_iteration_limit"),
+        Ok(OptionValue::Int(1000))
+    );
+}
+
+#[test]
+fn invalid_options_from_vars() {
+    assert_eq!(
+        quiet_highs().options_from_vars("HIGHS_OPT_", vec![("HIGHS_OPT_NOT_AN_OPTION", "1")]),
+        Err(OptionsEnvError::UnknownOption {
+            variable: "HIGHS_OPT_NOT_AN_OPTION".to_string(),
+            option: "not_an_option".to_string(),
+        })
+    );
+
+    // No option is set if a value can't be parsed.
+    let vars = vec![
+        ("HIGHS_OPT_SIMPLEX_ITERATION_LIMIT", "ten"),
+        ("HIGHS_OPT_TIME_LIMIT", "5"),
+    ];
+    let mut highs = quiet_highs();
+    let error = highs.options_from_vars("HIGHS_OPT_", vars).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "HIGHS_OPT_SIMPLEX_ITERATION_LIMIT: \"ten\" is not an integer"
+    );
+    assert_eq!(
+        highs.get_option("time_limit"),
+        Ok(OptionValue::Double(f64::INFINITY))
+    );
+
+    assert!(matches!(
+        quiet_highs().options_from_vars("HIGHS_OPT_", vec![("HIGHS_OPT_MIP_REL_GAP", "-1")]),
+        Err(OptionsEnvError::Rejected { .. })
+    ));
+}
+
+#[test]
+fn write_and_read_options() {
+    let mut highs = quiet_highs();
+    highs.set_double_option("time_limit", 30.).unwrap();
+
+    let all = temp_file("all.opt");
+    highs.write_options(&all).unwrap();
+    let all = fs::read_to_string(all).unwrap();
+    assert!(all.contains("time_limit = 30"), "{}", all);
+    assert!(all.contains("presolve = "), "{}", all);
+
+    let non_default = temp_file("non_default.opt");
+    highs.write_non_default_options(&non_default).unwrap();
+    let content = fs::read_to_string(&non_default).unwrap();
+    assert!(content.contains("time_limit = 30"), "{}", content);
+    assert!(!content.contains("presolve"), "{}", content);
+
+    let mut reread = quiet_highs();
+    reread.read_options(&non_default).unwrap();
+    assert_eq!(reread.get_double_option("time_limit"), Ok(30.));
+}
+
+#[test]
+fn read_invalid_options() {
+    let mut highs = quiet_highs();
+    let path = temp_file("invalid.opt");
+    fs::write(&path, "not_an_option = 1\n").unwrap();
+    assert!(matches!(
+        highs.read_options(&path),
+        Err(OptionsFileError::Read { .. })
+    ));
+    assert!(matches!(
+        highs.read_options(temp_file("missing.opt")),
+        Err(OptionsFileError::Path(PathError::Io { .. }))
+    ));
+}
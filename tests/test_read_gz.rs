@@ -0,0 +1,45 @@
+//! Reading gzip-compressed models needs HiGHS to be built with zlib.
+#![cfg(feature = "libz")]
+
+mod common;
+
+use std::ffi::CString;
+
+use common::fixture;
+use highs_sys::*;
+
+#[test]
+fn read_compressed_mps() {
+    // The LP from `test_highs_call.rs`, in gzipped free MPS format.
+    let path = fixture("highs_call.mps.gz");
+    let filename = CString::new(path.to_str().unwrap()).unwrap();
+    unsafe {
+        let highs = Highs_create();
+        let option_name = CString::new("output_flag").unwrap();
+        Highs_setBoolOptionValue(highs, option_name.as_ptr(), 0);
+
+        assert_eq!(
+            Highs_readModel(highs, filename.as_ptr()),
+            STATUS_OK,
+            "readModel"
+        );
+        assert_eq!(Highs_run(highs), STATUS_OK, "run");
+        assert_eq!(Highs_getModelStatus(highs), MODEL_STATUS_OPTIMAL);
+        assert_eq!(Highs_getObjectiveValue(highs), 16.);
+
+        let colvalue: &mut [f64] = &mut [0.; 2];
+        let coldual: &mut [f64] = &mut [0.; 2];
+        let rowvalue: &mut [f64] = &mut [0.; 3];
+        let rowdual: &mut [f64] = &mut [0.; 3];
+        Highs_getSolution(
+            highs,
+            colvalue.as_mut_ptr(),
+            coldual.as_mut_ptr(),
+            rowvalue.as_mut_ptr(),
+            rowdual.as_mut_ptr(),
+        );
+        assert_eq!(colvalue, &[2., 4.]);
+
+        Highs_destroy(highs);
+    }
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::small_lp;
+use highs_sys::*;
+
+// The tests that configure a thread count all set 1, as they share the process-wide scheduler
+// settings.
+
+#[test]
+fn configured_threads_apply_to_new_instances() {
+    set_global_scheduler_threads(1).unwrap();
+
+    let mut highs = Highs::new();
+    assert_eq!(highs.get_int_option("threads").unwrap(), 1);
+    highs.set_parallel(Parallel::Off).unwrap();
+    assert_eq!(highs.get_string_option("parallel").unwrap(), "off");
+
+    highs.set_bool_option("output_flag", false).unwrap();
+    highs.pass_model(&small_lp()).unwrap();
+    highs.run().unwrap();
+    assert_eq!(highs.model_status(), MODEL_STATUS_OPTIMAL);
+    assert_eq!(highs.objective_value(), 22.5);
+}
+
+#[test]
+fn unknown_option_is_an_error() {
+    let mut highs = Highs::new();
+    highs.set_bool_option("output_flag", false).unwrap();
+    assert_eq!(
+        highs.set_int_option("no_such_option", 1),
+        Err(HighsError::Status {
+            function: "Highs_setIntOptionValue"
+        })
+    );
+    assert!(matches!(
+        highs.set_string_option("solver", "simplex\0"),
+        Err(HighsError::NulByte(_))
+    ));
+}
+
+#[test]
+fn too_many_threads_are_rejected() {
+    let error = set_global_scheduler_threads(usize::MAX).unwrap_err();
+    assert_eq!(error, HighsError::TooManyThreads(usize::MAX));
+
+    let mut highs = Highs::new();
+    assert_eq!(highs.set_threads(usize::MAX), Err(error));
+}
+
+#[cfg(not(target_os = "windows"))] // like `highs_functions_multithread`
+#[test]
+fn concurrent_single_threaded_solves() {
+    // The pattern documented on `set_global_scheduler_threads`: one HiGHS thread per solve.
+    set_global_scheduler_threads(1).unwrap();
+    let threads: Vec<_> = (0..128)
+        .map(|_| {
+            std::thread::spawn(|| {
+                let mut highs = Highs::new();
+                highs.set_bool_option("output_flag", false).unwrap();
+                highs.set_parallel(Parallel::Off).unwrap();
+                highs.pass_model(&small_lp()).unwrap();
+                highs.run().unwrap();
+                assert_eq!(highs.model_status(), MODEL_STATUS_OPTIMAL);
+                highs.objective_value()
+            })
+        })
+        .collect();
+    for t in threads {
+        let objective = t.join().expect("Thread should not panic");
+        assert_eq!(objective, 22.5);
+    }
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use std::fmt::Debug;
+
+use common::{quiet_highs, read_fixture};
+use highs_sys::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn mip_features() -> Model {
+    Model::from_mps(&read_fixture("mip_features.mps")).unwrap()
+}
+
+/// Checks that `value` is the same after a round trip through JSON and MessagePack.
+fn assert_round_trips<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+    let json = serde_json::to_string(value).unwrap();
+    assert_eq!(
+        &serde_json::from_str::<T>(&json).unwrap(),
+        value,
+        "{}",
+        json
+    );
+    let msgpack = rmp_serde::to_vec_named(value).unwrap();
+    assert_eq!(&rmp_serde::from_slice::<T>(&msgpack).unwrap(), value);
+}
+
+#[test]
+fn model_round_trip() {
+    let model = mip_features();
+    assert_round_trips(&model);
+    assert_round_trips(&Model::default());
+}
+
+#[test]
+fn infinity_is_serialized_as_1e30() {
+    let inf = f64::INFINITY;
+    let model = Model {
+        col_cost: vec![1.],
+        col_lower: vec![-inf],
+        col_upper: vec![inf],
+        ..Model::default()
+    };
+    let json = serde_json::to_value(&model).unwrap();
+    assert_eq!(json["col_lower"], serde_json::json!([-1e30]));
+    assert_eq!(json["col_upper"], serde_json::json!([1e30]));
+
+    // Larger bounds are infinite too, like in HiGHS.
+    let json = serde_json::json!({
+        "sense": OBJECTIVE_SENSE_MINIMIZE,
+        "offset": 0.0,
+        "col_cost": [1.0],
+        "col_lower": [-1e31],
+        "col_upper": [1e30],
+        "row_lower": [],
+        "row_upper": [],
+        "a_format": MATRIX_FORMAT_COLUMN_WISE,
+        "a_matrix": {"start": [0], "index": [], "value": []},
+        "hessian": {"start": [], "index": [], "value": []},
+        "integrality": []
+    });
+    let parsed: Model = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed.col_lower, [-inf]);
+    assert_eq!(parsed.col_upper, [inf]);
+}
+
+#[test]
+fn solution_and_basis_round_trip() {
+    assert_round_trips(&Solution {
+        col_value: vec![2., 4.],
+        col_dual: vec![0., 0.],
+        row_value: vec![4., 10., 8.],
+        row_dual: vec![0., 4. / 3., 1. / 3.],
+    });
+    assert_round_trips(&Basis {
+        col_status: vec![kHighsBasisStatusBasic, kHighsBasisStatusLower],
+        row_status: vec![kHighsBasisStatusUpper],
+    });
+}
+
+#[test]
+fn option_and_info_values() {
+    let values = [
+        OptionValue::Bool(true),
+        OptionValue::Int(-3),
+        OptionValue::Double(f64::INFINITY),
+        OptionValue::String("ipm".to_string()),
+    ];
+    assert_eq!(
+        serde_json::to_value(&values).unwrap(),
+        serde_json::json!([{"bool": true}, {"int": -3}, {"double": 1e30}, {"string": "ipm"}])
+    );
+    for value in &values {
+        assert_round_trips(value);
+    }
+
+    let infos = [
+        InfoValue::Int(7),
+        InfoValue::Int64(1 << 40),
+        InfoValue::Double(-2.75),
+    ];
+    assert_eq!(
+        serde_json::to_value(&infos).unwrap(),
+        serde_json::json!([{"int": 7}, {"int64": 1_i64 << 40}, {"double": -2.75}])
+    );
+    for info in &infos {
+        assert_round_trips(info);
+    }
+}
+
+#[test]
+fn highs_values_round_trip() {
+    let mut highs = quiet_highs();
+    highs.pass_model(&mip_features()).unwrap();
+    highs.run().unwrap();
+
+    assert_round_trips(&highs.get_model(MATRIX_FORMAT_COLUMN_WISE).unwrap());
+    assert_round_trips(&highs.get_solution().unwrap());
+    assert_round_trips(&highs.get_option("time_limit").unwrap());
+    assert_round_trips(&highs.get_info("objective_function_value").unwrap());
+    assert_round_trips(&highs.get_info("mip_node_count").unwrap());
+}
+
+#[test]
+fn infinite_costs_and_offset() {
+    let model = Model {
+        offset: f64::NEG_INFINITY,
+        col_cost: vec![f64::INFINITY],
+        col_lower: vec![0.],
+        col_upper: vec![1.],
+        ..Model::default()
+    };
+    let json = serde_json::to_value(&model).unwrap();
+    assert_eq!(json["offset"], serde_json::json!(-1e30));
+    assert_eq!(json["col_cost"], serde_json::json!([1e30]));
+    assert_round_trips(&model);
+}
+
+#[test]
+fn invalid_models_are_rejected() {
+    let mut json = serde_json::to_value(mip_features()).unwrap();
+    json["col_upper"] = serde_json::json!([1.0]);
+    let error = serde_json::from_value::<Model>(json).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid model: col_upper has 1 entries instead of 5"
+    );
+
+    let mut json = serde_json::to_value(mip_features()).unwrap();
+    json["a_matrix"]["index"][0] = serde_json::json!(100);
+    assert!(serde_json::from_value::<Model>(json).is_err());
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use std::fs;
+
+use common::{fixture, quiet_highs, temp_file};
+use highs_sys::*;
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(
+        actual.len(),
+        expected.len(),
+        "{:?} != {:?}",
+        actual,
+        expected
+    );
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn parse_solution_file() {
+    let content = fs::read_to_string(fixture("highs_call.sol")).unwrap();
+    let solution = Solution::from_solution_file(&content).unwrap();
+    assert_eq!(solution.col_value, [2., 4.]);
+    assert_eq!(solution.col_dual, [0., 0.]);
+    assert_eq!(solution.row_value, [4., 10., 8.]);
+    assert_close(&solution.row_dual, &[0., 4. / 3., 1. / 3.]);
+}
+
+#[test]
+fn parse_solution_without_duals() {
+    let content = "Model status
+Optimal
+
+# Primal solution values
+Feasible
+Objective 1
+# Columns 1
+1
+# Rows 0
+
+# Dual solution values
+None
+";
+    assert_eq!(
+        Solution::from_solution_file(content).unwrap(),
+        Solution {
+            col_value: vec![1.],
+            ..Solution::default()
+        }
+    );
+}
+
+#[test]
+fn parse_errors_have_line_numbers() {
+    let error = |content: &str| match Solution::from_solution_file(content).unwrap_err() {
+        SolutionFileError::Parse { line, expected } => (line, expected),
+        error => panic!("{}", error),
+    };
+    assert_eq!(error(""), (0, "# Primal solution values"));
+    assert_eq!(
+        error("# Primal solution values\nFeasible\nObjective 1\n# Columns 1\nx one\n"),
+        (5, "a value")
+    );
+    assert_eq!(
+        error("# Primal solution values\nNone\n\n# Dual solution values\nUnknown\n"),
+        (5, "a solution status")
+    );
+    let content = "# Primal solution values\nFeasible\nObjective 1\n# Rows 1\n";
+    assert_eq!(
+        Solution::from_solution_file(content)
+            .unwrap_err()
+            .to_string(),
+        "line 4: expected # Columns"
+    );
+}
+
+#[test]
+fn write_and_read_solution() {
+    let mut highs = quiet_highs();
+    highs.read_model(fixture("highs_call.mps")).unwrap();
+    highs.run().unwrap();
+    let solution = highs.get_solution().unwrap();
+
+    let path = temp_file("highs_call.sol");
+    highs.write_solution(&path).unwrap();
+    let parsed = Solution::from_solution_file(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_close(&parsed.col_value, &solution.col_value);
+    assert_close(&parsed.col_dual, &solution.col_dual);
+    assert_close(&parsed.row_value, &solution.row_value);
+    assert_close(&parsed.row_dual, &solution.row_dual);
+
+    let mut reread = quiet_highs();
+    reread.read_model(fixture("highs_call.mps")).unwrap();
+    reread.read_solution(&path).unwrap();
+    assert_close(&reread.get_solution().unwrap().col_value, &[2., 4.]);
+
+    let pretty = temp_file("highs_call_pretty.sol");
+    highs.write_solution_pretty(&pretty).unwrap();
+    assert!(!fs::read_to_string(&pretty).unwrap().is_empty());
+}
+
+#[test]
+fn read_missing_solution_file() {
+    let mut highs = quiet_highs();
+    highs.read_model(fixture("highs_call.mps")).unwrap();
+    let error = highs.read_solution(temp_file("missing.sol")).unwrap_err();
+    assert!(
+        matches!(error, SolutionFileError::Path(PathError::Io { .. })),
+        "{:?}",
+        error
+    );
+}
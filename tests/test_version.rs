@@ -0,0 +1,27 @@
+use std::ffi::CStr;
+
+use highs_sys::*;
+
+#[test]
+fn linked_version_matches_headers() {
+    assert!(check_linked_version());
+}
+
+#[test]
+fn version_string() {
+    let version = unsafe { CStr::from_ptr(Highs_version()) };
+    assert_eq!(
+        version.to_str().unwrap(),
+        format!(
+            "{}.{}.{}",
+            HIGHS_VERSION_MAJOR, HIGHS_VERSION_MINOR, HIGHS_VERSION_PATCH
+        )
+    );
+}
+
+#[test]
+fn highs_int_size_matches() {
+    check_highs_int_size();
+    let expected = if cfg!(feature = "highsint64") { 8 } else { 4 };
+    assert_eq!(std::mem::size_of::<HighsInt>(), expected);
+}